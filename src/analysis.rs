@@ -0,0 +1,317 @@
+//! Measurements of the structure of a finished maze.
+
+use core::ops::{Add, Div, Mul};
+use std::collections::{HashMap, VecDeque};
+
+use strum::IntoEnumIterator;
+
+use crate::board::Board;
+use crate::direction::Direction;
+use crate::maze::Maze;
+use crate::pair::Pair;
+use crate::solver::UnitCost;
+use crate::tile::Tile;
+
+/// How much each cell on the solution path adds to the difficulty.
+const SOLUTION_WEIGHT: f64 = 2.0;
+/// How much each dead end adds to the difficulty.
+const DEAD_END_WEIGHT: f64 = 1.0;
+/// How much each junction adds to the difficulty.
+const JUNCTION_WEIGHT: f64 = 0.5;
+/// How much each cell of average branch depth adds to the difficulty.
+const BRANCH_DEPTH_WEIGHT: f64 = 1.0;
+
+impl Maze {
+    /// Scores how hard the maze is to solve.
+    ///
+    /// The score is
+    /// `(2 * solution + dead_ends + 0.5 * junctions + branch_depth) / cells`,
+    /// where `solution` is the number of cells on the shortest path between
+    /// the first two entrances, `dead_ends` and `junctions` count the cells
+    /// with one and at least three openings, `branch_depth` is the average
+    /// distance from each dead end back to the solution path, and `cells` is
+    /// the number of cells in the maze.
+    ///
+    /// ### Returns
+    /// * The difficulty, where longer and twistier mazes score higher.
+    #[inline]
+    #[must_use]
+    pub fn difficulty(&self) -> f64 {
+        let entries = self.entry_cells();
+        let solution = match *entries.as_slice() {
+            [start, end, ..] => self
+                .solve_weighted(start, end, &UnitCost)
+                .map(|(path, _)| path)
+                .unwrap_or_default(),
+            _ => vec![],
+        };
+
+        let mut dead_ends = Vec::<Pair>::new();
+        let mut junctions = 0_u32;
+        for row in 0..self.board.cell_height {
+            for col in 0..self.board.cell_width {
+                let Some(cell) = Board::<Tile>::cell_position_to_pair(row, col)
+                else {
+                    continue;
+                };
+                match self.count_openings(cell) {
+                    1 => dead_ends.push(cell),
+                    3.. => junctions = junctions.add(1),
+                    _ => {}
+                }
+            }
+        }
+
+        let distances = self.distances_from(&solution);
+        let branch_depths = dead_ends
+            .iter()
+            .filter_map(|cell| *distances.get_from_pair(*cell)?)
+            .map(f64::from)
+            .collect::<Vec<f64>>();
+        let average_branch_depth = if branch_depths.is_empty() {
+            0.0
+        } else {
+            branch_depths
+                .iter()
+                .sum::<f64>()
+                .div(branch_depths.len() as f64)
+        };
+
+        let cells =
+            self.board.cell_height.mul(self.board.cell_width).max(1) as f64;
+        SOLUTION_WEIGHT
+            .mul(solution.len() as f64)
+            .add(DEAD_END_WEIGHT.mul(dead_ends.len() as f64))
+            .add(JUNCTION_WEIGHT.mul(f64::from(junctions)))
+            .add(BRANCH_DEPTH_WEIGHT.mul(average_branch_depth))
+            .div(cells)
+    }
+
+    /// Counts the wall tiles in the maze.
+    ///
+    /// ### Returns
+    /// * The number of `Tile::Wall` tiles across the whole board.
+    #[inline]
+    #[must_use]
+    pub fn count_walls(&self) -> usize {
+        self.board.count_where(|tile| *tile == Tile::Wall)
+    }
+
+    /// Counts the passable tiles in the maze.
+    ///
+    /// ### Returns
+    /// * The number of paths, entrances and items across the whole board.
+    #[inline]
+    #[must_use]
+    pub fn count_paths(&self) -> usize {
+        self.board.count_where(Tile::is_passable)
+    }
+
+    /// Converts the maze into an adjacency list graph.
+    ///
+    /// ### Returns
+    /// * A map from the tile `Pair` of every cell to the cells it is
+    ///   connected to, sorted by row and then column.
+    #[inline]
+    #[must_use]
+    pub fn adjacency(&self) -> HashMap<Pair, Vec<Pair>> {
+        (0..self.board.cell_height)
+            .flat_map(|row| {
+                (0..self.board.cell_width).map(move |col| (row, col))
+            })
+            .filter_map(|(row, col)| {
+                Board::<Tile>::cell_position_to_pair(row, col)
+            })
+            .map(|cell| {
+                let mut neighbors = if self
+                    .board
+                    .get_from_pair(cell)
+                    .is_some_and(Tile::is_passable)
+                {
+                    self.connected_cells(cell)
+                } else {
+                    vec![]
+                };
+                neighbors.sort_unstable();
+                (cell, neighbors)
+            })
+            .collect()
+    }
+
+    /// Finds every dead end along with the way out of it.
+    ///
+    /// ### Returns
+    /// * Each cell with exactly one opening and the `Direction` of that
+    ///   opening, in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn dead_ends_with_direction(&self) -> Vec<(Pair, Direction)> {
+        (0..self.board.cell_height)
+            .flat_map(|row| {
+                (0..self.board.cell_width).map(move |col| (row, col))
+            })
+            .filter_map(|(row, col)| {
+                Board::<Tile>::cell_position_to_pair(row, col)
+            })
+            .filter_map(|cell| {
+                let mut openings = self.openings(cell);
+                let direction = openings.next()?;
+                openings.next().is_none().then_some((cell, direction))
+            })
+            .collect()
+    }
+
+    /// Gets the directions of the carved walls and entrances around a cell.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to check.
+    ///
+    /// ### Returns
+    /// * An iterator over the directions that can be walked out of the cell.
+    fn openings(&self, cell: Pair) -> impl Iterator<Item = Direction> + '_ {
+        Direction::iter().filter(move |direction| {
+            self.board
+                .get_from_pair(cell.add(Pair::from(*direction)))
+                .is_some_and(Tile::is_passable)
+        })
+    }
+
+    /// Counts the carved walls and entrances around a cell.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to check.
+    ///
+    /// ### Returns
+    /// * The number of directions that can be walked out of the cell.
+    fn count_openings(&self, cell: Pair) -> usize {
+        self.openings(cell).count()
+    }
+
+    /// Finds the distance in cells from the nearest of some starting cells.
+    ///
+    /// ### Parameters
+    /// * `starts`: The cells to measure distances from.
+    ///
+    /// ### Returns
+    /// * A `Board` holding the distance to every reachable cell.
+    fn distances_from(&self, starts: &[Pair]) -> Board<Option<u32>> {
+        let mut distances = Board::<Option<u32>>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        let mut queue = VecDeque::<(Pair, u32)>::new();
+
+        for start in starts {
+            if let Some(distance) = distances.get_mut_from_pair(*start) {
+                *distance = Some(0);
+                queue.push_back((*start, 0));
+            }
+        }
+
+        while let Some((cell, distance)) = queue.pop_front() {
+            for neighbor in self.connected_cells(cell) {
+                if let Some(slot) = distances.get_mut_from_pair(neighbor) {
+                    if slot.is_none() {
+                        *slot = Some(distance.add(1));
+                        queue.push_back((neighbor, distance.add(1)));
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+#[cfg(test)]
+mod test_analysis {
+    use crate::{
+        direction::Direction, maze::Maze, pair::Pair,
+        test_support::maze_from_ascii,
+    };
+
+    #[test]
+    fn test_adjacency() {
+        let Some(maze) = Maze::from_backtracking(5, 6) else {
+            panic!("failed to generate maze");
+        };
+        let adjacency = maze.adjacency();
+        let passages = maze.passages();
+
+        assert_eq!(adjacency.len(), 5 * 6);
+        for (cell, neighbors) in &adjacency {
+            let degree = passages
+                .iter()
+                .filter(|edge| edge.pairs.0 == *cell || edge.pairs.1 == *cell)
+                .count();
+            assert_eq!(neighbors.len(), degree);
+            assert!(neighbors.is_sorted());
+            assert!(neighbors
+                .iter()
+                .all(|neighbor| adjacency[neighbor].contains(cell)));
+        }
+    }
+
+    #[test]
+    fn test_count_walls_and_paths() {
+        let Some(maze) = Maze::from_backtracking(7, 4) else {
+            panic!("failed to generate maze");
+        };
+
+        assert_eq!(
+            maze.count_walls() + maze.count_paths(),
+            maze.board.tile_height() * maze.board.tile_width()
+        );
+        // a perfect maze carves every cell, the walls between them and two
+        // entrances
+        assert_eq!(maze.count_paths(), 7 * 4 + (7 * 4 - 1) + 2);
+        assert_eq!(Maze::new(2, 2).count_walls(), 25);
+    }
+
+    #[test]
+    fn test_dead_ends_with_direction() {
+        #[rustfmt::skip]
+        let maze = maze_from_ascii(&[
+            "#E###",
+            "#   #",
+            "# ###",
+            "#   #",
+            "#####",
+        ]);
+
+        assert_eq!(
+            maze.dead_ends_with_direction(),
+            vec![
+                (Pair::from_row_and_col(1, 3), Direction::Left),
+                (Pair::from_row_and_col(3, 3), Direction::Left),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_difficulty() {
+        #[rustfmt::skip]
+        let twisty = maze_from_ascii(&[
+            "#######",
+            "E     #",
+            "##### #",
+            "#     #",
+            "# #####",
+            "#     E",
+            "#######",
+        ]);
+        #[rustfmt::skip]
+        let trivial = maze_from_ascii(&[
+            "#######",
+            "E     E",
+            "# # # #",
+            "#     #",
+            "# # # #",
+            "#     #",
+            "#######",
+        ]);
+
+        assert!(twisty.difficulty() > trivial.difficulty());
+        assert!(Maze::new(3, 3).difficulty() < trivial.difficulty());
+    }
+}
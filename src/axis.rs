@@ -0,0 +1,11 @@
+//! A module detailing the axes a maze can be mirrored across.
+
+/// The line a maze is reflected across.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Axis {
+    /// A horizontal line, mirroring the top half onto the bottom half.
+    #[default]
+    Horizontal,
+    /// A vertical line, mirroring the left half onto the right half.
+    Vertical,
+}
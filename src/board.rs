@@ -1,13 +1,17 @@
 //! A 2D vector meant to interface cleanly with pair access.
 
 use core::fmt::{Debug, Display, Formatter, Result};
-use core::ops::{Add, Mul};
+use core::mem;
+use core::ops::{Add, Div, Mul};
 
+use crate::maze_error::MazeError;
 use crate::pair::Pair;
+use crate::tile::Tile;
 
 /// The number of tiles to jump to get to the next cell.
 pub const CELL_STEP: i32 = 2_i32;
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Board<T>
 where
     T: Sized,
@@ -45,24 +49,137 @@ where
         }
     }
 
-    /// Converts the cell position to an index.
+    /// Copies a rectangle of the board into a new `Board`.
+    ///
+    /// The region is measured in tiles rather than cells, so a crop starting
+    /// on an odd row or column begins inside a cell rather than on its wall.
+    /// Every `Board` is an odd number of tiles along each side, so only
+    /// regions of `2n + 1` by `2m + 1` tiles can be cropped; an even height
+    /// or width gives `None` even when it fits on the board.
     ///
     /// ### Parameters
-    /// * `position`: The value to convert.
+    /// * `top_left`: The `Pair` of the first tile to copy.
+    /// * `height`: The number of tile rows to copy.
+    /// * `width`: The number of tile columns to copy.
     ///
     /// ### Returns
-    /// * The index relating to the cell position.
+    /// * The cropped `Board`, or `None` if the region exceeds the board or
+    ///   does not have `2n + 1` rows and columns.
     ///
     /// ### Examples
     /// ```
-    /// use maze_generation::board::Board;
+    /// use maze_generation::{board::Board, pair::Pair};
     ///
-    /// assert_eq!(Board::<i32>::cell_position_to_index(4), 9);
+    /// let board = Board::<i32>::new(3, 3);
+    /// let crop = board.subgrid(Pair::from_row_and_col(2, 2), 3, 5);
+    /// assert_eq!(crop.map(|crop| crop.tile_width()), Some(5));
+    ///
+    /// assert!(board.subgrid(Pair::from_row_and_col(4, 4), 5, 5).is_none());
+    /// assert!(board.subgrid(Pair::ZERO, 2, 4).is_none());
     /// ```
     #[inline]
     #[must_use]
-    pub fn cell_position_to_index(position: usize) -> usize {
-        position.mul(CELL_STEP as usize).add(1)
+    pub fn subgrid(
+        &self,
+        top_left: Pair,
+        height: usize,
+        width: usize,
+    ) -> Option<Self> {
+        let (top, left) = self.indices_from_pair(top_left)?;
+        let grid = self
+            .grid
+            .get(top..top.checked_add(height)?)?
+            .iter()
+            .map(|row| Some(row.get(left..left.checked_add(width)?)?.to_vec()))
+            .collect::<Option<Vec<Vec<T>>>>()?;
+
+        Board::from_grid(grid)
+    }
+}
+
+impl<T> Board<T>
+where
+    T: Clone + Sized,
+{
+    /// Copies another board over this one, overwriting any overlapped values.
+    ///
+    /// ### Parameters
+    /// * `other`: The `Board` to copy from.
+    /// * `at`: The `Pair` of the tile to place `other`'s top-left tile on.
+    ///
+    /// ### Returns
+    /// * `Some(())` if the board was pasted, or `None` if it wouldn't fit,
+    ///   in which case nothing is copied.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{board::Board, pair::Pair};
+    ///
+    /// let mut board = Board::<i32>::new(2, 2);
+    /// let piece = Board::<i32>::new(1, 1);
+    /// assert_eq!(board.paste(&piece, Pair::from_row_and_col(2, 2)), Some(()));
+    /// assert_eq!(board.paste(&piece, Pair::from_row_and_col(3, 3)), None);
+    /// ```
+    #[inline]
+    pub fn paste(&mut self, other: &Self, at: Pair) -> Option<()> {
+        let (top, left) = self.indices_from_pair(at)?;
+        let fits = |start: usize, length: usize, available: usize| {
+            start
+                .checked_add(length)
+                .is_some_and(|end| end <= available)
+        };
+        if !fits(top, other.tile_height(), self.tile_height())
+            || !fits(left, other.tile_width(), self.tile_width())
+        {
+            return None;
+        }
+
+        for (row, source) in self.grid.iter_mut().skip(top).zip(other.rows()) {
+            row.get_mut(left..left.add(source.len()))?
+                .clone_from_slice(source);
+        }
+
+        Some(())
+    }
+
+    /// Rotates the board a quarter turn clockwise.
+    ///
+    /// ### Returns
+    /// * A new `Board` whose first row is this board's first column read
+    ///   from the bottom up, with the cell dimensions swapped.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{board::Board, pair::Pair};
+    ///
+    /// let mut board = Board::<i32>::new(1, 2);
+    /// if let Some(value) = board.get_mut_from_pair(Pair::from_row_and_col(0, 4)) {
+    ///     *value = 1;
+    /// }
+    ///
+    /// let rotated = board.rotate_90();
+    /// assert_eq!((rotated.cell_height, rotated.cell_width), (2, 1));
+    /// assert_eq!(rotated.get_from_pair(Pair::from_row_and_col(4, 2)), Some(&1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rotate_90(&self) -> Self {
+        let width = self.grid.first().map_or(0, Vec::len);
+        let grid = (0..width)
+            .map(|col| {
+                self.grid
+                    .iter()
+                    .rev()
+                    .filter_map(|row| row.get(col).cloned())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            grid,
+            cell_width: self.cell_height,
+            cell_height: self.cell_width,
+        }
     }
 }
 
@@ -74,7 +191,7 @@ where
     fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
         let mut result = String::new();
 
-        for row in &self.grid {
+        for row in self.rows() {
             for cell in row {
                 result.push_str(cell.to_string().as_str());
             }
@@ -99,6 +216,236 @@ impl<T> Board<T>
 where
     T: Sized,
 {
+    /// Creates a `Board` from an existing grid of values.
+    ///
+    /// ### Parameters
+    /// * `grid`: The rows of values, which must all have the same length.
+    ///
+    /// ### Returns
+    /// * The `Board`, or `None` if the grid is not rectangular or does not
+    ///   have `2n + 1` rows and columns.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::board::Board;
+    ///
+    /// let board = Board::from_grid(vec![vec![0; 5]; 3]);
+    /// assert_eq!(board.map(|board| board.cell_width), Some(2));
+    ///
+    /// assert!(Board::from_grid(vec![vec![0; 4]; 3]).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_grid(grid: Vec<Vec<T>>) -> Option<Self> {
+        let to_cells = |length: usize| {
+            (!length.is_multiple_of(CELL_STEP as usize))
+                .then(|| length.div(CELL_STEP as usize))
+        };
+
+        let cell_height = to_cells(grid.len())?;
+        let cell_width = to_cells(grid.first()?.len())?;
+        let tile_width = Self::cell_position_to_index(cell_width);
+        if grid.iter().any(|row| row.len() != tile_width) {
+            return None;
+        }
+
+        Some(Self {
+            grid,
+            cell_width,
+            cell_height,
+        })
+    }
+
+    /// Converts the cell position to an index.
+    ///
+    /// ### Parameters
+    /// * `position`: The value to convert.
+    ///
+    /// ### Returns
+    /// * The index relating to the cell position.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::board::Board;
+    ///
+    /// assert_eq!(Board::<i32>::cell_position_to_index(4), 9);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cell_position_to_index(position: usize) -> usize {
+        position.mul(CELL_STEP as usize).add(1)
+    }
+
+    /// Converts a cell's row and column into the `Pair` of its tile.
+    ///
+    /// ### Parameters
+    /// * `row`: The cell row.
+    /// * `col`: The cell column.
+    ///
+    /// ### Returns
+    /// * The `Pair` of the cell's tile, or `None` if it overflows.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{board::Board, pair::Pair};
+    ///
+    /// assert_eq!(
+    ///     Board::<i32>::cell_position_to_pair(1, 2),
+    ///     Some(Pair::from_row_and_col(3, 5))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cell_position_to_pair(row: usize, col: usize) -> Option<Pair> {
+        Some(Pair::from_row_and_col(
+            i32::try_from(Self::cell_position_to_index(row)).ok()?,
+            i32::try_from(Self::cell_position_to_index(col)).ok()?,
+        ))
+    }
+
+    /// Gets the number of tiles per row.
+    ///
+    /// ### Returns
+    /// * The width of the grid in tiles.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::board::Board;
+    ///
+    /// let board = Board::<i32>::new(4, 4);
+    /// assert_eq!(board.cell_width, 4);
+    /// assert_eq!(board.tile_width(), 9);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tile_width(&self) -> usize {
+        Self::cell_position_to_index(self.cell_width)
+    }
+
+    /// Gets the number of tiles per column.
+    ///
+    /// ### Returns
+    /// * The height of the grid in tiles.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::board::Board;
+    ///
+    /// let board = Board::<i32>::new(4, 4);
+    /// assert_eq!(board.cell_height, 4);
+    /// assert_eq!(board.tile_height(), 9);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tile_height(&self) -> usize {
+        Self::cell_position_to_index(self.cell_height)
+    }
+
+    /// Iterates over the rows of the board.
+    ///
+    /// ### Returns
+    /// * An iterator yielding each row as a slice.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.grid.iter().map(Vec::as_slice)
+    }
+
+    /// Iterates mutably over the rows of the board.
+    ///
+    /// ### Returns
+    /// * An iterator yielding each row as a mutable slice.
+    #[inline]
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.grid.iter_mut().map(Vec::as_mut_slice)
+    }
+
+    /// Iterates over the cells on the border of the board.
+    ///
+    /// Only the border is walked, so this takes time proportional to the
+    /// height plus the width rather than the whole board.
+    ///
+    /// ### Returns
+    /// * An iterator yielding the `Pair` of each border cell exactly once, in
+    ///   row-major order.
+    #[inline]
+    pub fn perimeter_cells(&self) -> impl Iterator<Item = Pair> + '_ {
+        let (height, width) = (self.cell_height, self.cell_width);
+        (0..height)
+            .flat_map(move |row| {
+                // inner rows only touch the border at their first and last
+                // columns, which are the same column when the board is one
+                // cell wide
+                let step = if row == 0 || row.add(1) == height {
+                    1
+                } else {
+                    width.saturating_sub(1).max(1)
+                };
+                (0..width).step_by(step).map(move |col| (row, col))
+            })
+            .filter_map(|(row, col)| Self::cell_position_to_pair(row, col))
+    }
+
+    /// Iterates over every value on the board along with its `Pair`.
+    ///
+    /// ### Returns
+    /// * An iterator over the board in row-major order.
+    #[inline]
+    #[must_use]
+    pub const fn iter_cells(&self) -> Cells<'_, T> {
+        Cells {
+            board: self,
+            row: 0,
+            col: 0,
+        }
+    }
+
+    /// Iterates mutably over every value on the board along with its `Pair`.
+    ///
+    /// ### Returns
+    /// * An iterator over the board in row-major order.
+    #[inline]
+    pub fn iter_mut_cells(&mut self) -> impl Iterator<Item = (Pair, &mut T)> {
+        self.grid.iter_mut().enumerate().flat_map(|(row, values)| {
+            values
+                .iter_mut()
+                .enumerate()
+                .filter_map(move |(col, value)| {
+                    let pair = Pair::from_row_and_col(
+                        i32::try_from(row).ok()?,
+                        i32::try_from(col).ok()?,
+                    );
+                    Some((pair, value))
+                })
+        })
+    }
+
+    /// Counts the values on the board that match a predicate.
+    ///
+    /// ### Parameters
+    /// * `predicate`: The test each value is checked against.
+    ///
+    /// ### Returns
+    /// * The number of values for which `predicate` is `true`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::board::Board;
+    ///
+    /// let board = Board::<i32>::new(1, 1);
+    /// assert_eq!(board.count_where(|value| *value == 0), 9);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn count_where<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.rows()
+            .flatten()
+            .filter(|value| predicate(value))
+            .count()
+    }
+
     /// Gets an immutable reference to a board based on some pair.
     ///
     /// ### Parameters
@@ -132,4 +479,471 @@ where
         let col_index = usize::try_from(pair.col).ok()?;
         row.get_mut(col_index)
     }
+
+    /// Gets an immutable reference to a board based on some pair without
+    /// checking that the pair is on the board.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` object used to access the board.
+    ///
+    /// ### Returns
+    /// * An immutable reference to a cell in the board.
+    ///
+    /// ### Safety
+    /// Both co-ordinates of `pair` must be non-negative and less than the
+    /// number of rows and the length of that row respectively, that is
+    /// `get_from_pair(pair)` must return `Some`. Anything else is undefined
+    /// behavior.
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_unchecked_from_pair(&self, pair: Pair) -> &T {
+        // SAFETY: the caller guarantees both indices are in bounds.
+        unsafe {
+            self.grid
+                .get_unchecked(pair.row as usize)
+                .get_unchecked(pair.col as usize)
+        }
+    }
+
+    /// Gets a mutable reference to a board based on some pair without
+    /// checking that the pair is on the board.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` object used to access the board.
+    ///
+    /// ### Returns
+    /// * A mutable reference to a cell in the board.
+    ///
+    /// ### Safety
+    /// Both co-ordinates of `pair` must be non-negative and less than the
+    /// number of rows and the length of that row respectively, that is
+    /// `get_mut_from_pair(pair)` must return `Some`. Anything else is
+    /// undefined behavior.
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_unchecked_mut_from_pair(&mut self, pair: Pair) -> &mut T {
+        // SAFETY: the caller guarantees both indices are in bounds.
+        unsafe {
+            self.grid
+                .get_unchecked_mut(pair.row as usize)
+                .get_unchecked_mut(pair.col as usize)
+        }
+    }
+
+    /// Gets an immutable reference to a board based on some pair, wrapping
+    /// around to the opposite edge when the pair is off the board.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` object used to access the board.
+    ///
+    /// ### Returns
+    /// * An immutable reference to the tile the pair wraps onto.
+    ///
+    /// ### Panics
+    /// * If the board has no tiles, which `Board::new` never produces.
+    #[inline]
+    #[must_use]
+    pub fn get_wrapping(&self, pair: Pair) -> &T {
+        let row = &self.grid[Self::wrap_index(pair.row, self.grid.len())];
+        &row[Self::wrap_index(pair.col, row.len())]
+    }
+
+    /// Gets a mutable reference to a board based on some pair, wrapping
+    /// around to the opposite edge when the pair is off the board.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` object used to access the board.
+    ///
+    /// ### Returns
+    /// * A mutable reference to the tile the pair wraps onto.
+    ///
+    /// ### Panics
+    /// * If the board has no tiles, which `Board::new` never produces.
+    #[inline]
+    #[must_use]
+    pub fn get_mut_wrapping(&mut self, pair: Pair) -> &mut T {
+        let height = self.grid.len();
+        let row = &mut self.grid[Self::wrap_index(pair.row, height)];
+        let width = row.len();
+        &mut row[Self::wrap_index(pair.col, width)]
+    }
+
+    /// Swaps the values at two pairs.
+    ///
+    /// ### Parameters
+    /// * `first`: The `Pair` of the first value.
+    /// * `second`: The `Pair` of the second value.
+    ///
+    /// ### Returns
+    /// * `Some(())` if the values were swapped, or `None` if either pair is
+    ///   out of bounds.
+    #[inline]
+    pub fn swap(&mut self, first: Pair, second: Pair) -> Option<()> {
+        let (first_row, first_col) = self.indices_from_pair(first)?;
+        let (second_row, second_col) = self.indices_from_pair(second)?;
+
+        if first_row == second_row {
+            self.grid.get_mut(first_row)?.swap(first_col, second_col);
+            return Some(());
+        }
+
+        let ((top_row, top_col), (bottom_row, bottom_col)) =
+            if first_row < second_row {
+                ((first_row, first_col), (second_row, second_col))
+            } else {
+                ((second_row, second_col), (first_row, first_col))
+            };
+        let (top, bottom) = self.grid.split_at_mut(bottom_row);
+        mem::swap(
+            top.get_mut(top_row)?.get_mut(top_col)?,
+            bottom.first_mut()?.get_mut(bottom_col)?,
+        );
+
+        Some(())
+    }
+
+    /// Wraps a co-ordinate onto an axis as if its ends were joined.
+    ///
+    /// ### Parameters
+    /// * `index`: The co-ordinate to wrap.
+    /// * `len`: The number of tiles along the axis.
+    ///
+    /// ### Returns
+    /// * The co-ordinate taken modulo `len`.
+    fn wrap_index(index: i32, len: usize) -> usize {
+        let len = i64::try_from(len).unwrap_or(i64::MAX);
+        usize::try_from(i64::from(index).rem_euclid(len)).unwrap_or_default()
+    }
+
+    /// Converts a pair into grid indices if it lies on the board.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` to convert.
+    ///
+    /// ### Returns
+    /// * The row and column indices, or `None` if the pair is out of bounds.
+    fn indices_from_pair(&self, pair: Pair) -> Option<(usize, usize)> {
+        let row_index = usize::try_from(pair.row).ok()?;
+        let col_index = usize::try_from(pair.col).ok()?;
+
+        (col_index < self.grid.get(row_index)?.len())
+            .then_some((row_index, col_index))
+    }
+}
+
+impl TryFrom<Vec<Vec<Tile>>> for Board<Tile> {
+    type Error = MazeError;
+
+    /// Converts a grid of tiles into a `Board`.
+    ///
+    /// ### Parameters
+    /// * `grid`: The rows of tiles.
+    ///
+    /// ### Returns
+    /// * The `Board`, or `MazeError::InvalidDimensions` if the grid is not
+    ///   rectangular or does not have `2n + 1` rows and columns.
+    #[inline]
+    fn try_from(
+        grid: Vec<Vec<Tile>>,
+    ) -> core::result::Result<Self, Self::Error> {
+        Self::from_grid(grid).ok_or(MazeError::InvalidDimensions)
+    }
+}
+
+/// An iterator over the values of a `Board` and their `Pairs`.
+pub struct Cells<'board, T>
+where
+    T: Sized,
+{
+    /// The board being iterated over.
+    board: &'board Board<T>,
+    /// The row of the next value.
+    row: usize,
+    /// The column of the next value.
+    col: usize,
+}
+
+impl<'board, T> Iterator for Cells<'board, T>
+where
+    T: Sized,
+{
+    type Item = (Pair, &'board T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let row = self.board.grid.get(self.row)?;
+
+            let Some(value) = row.get(self.col) else {
+                self.row = self.row.add(1);
+                self.col = 0;
+                continue;
+            };
+
+            let pair = Pair::from_row_and_col(
+                i32::try_from(self.row).ok()?,
+                i32::try_from(self.col).ok()?,
+            );
+            self.col = self.col.add(1);
+            return Some((pair, value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_board {
+    use core::ops::{Add, Mul};
+
+    use crate::{
+        board::Board, maze::Maze, maze_error::MazeError, pair::Pair, tile::Tile,
+    };
+
+    #[test]
+    fn test_new_orientation() {
+        let board = Board::<i32>::new(3, 5);
+
+        assert_eq!(board.grid.len(), 7);
+        assert!(board.grid.iter().all(|row| row.len() == 11));
+        assert!(board.get_from_pair(Pair::from_row_and_col(6, 10)).is_some());
+        assert!(board.get_from_pair(Pair::from_row_and_col(10, 6)).is_none());
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut board = Board::<i32>::new(2, 2);
+        let first = Pair::from_row_and_col(1, 1);
+        let second = Pair::from_row_and_col(3, 2);
+        if let Some(value) = board.get_mut_from_pair(first) {
+            *value = 1;
+        }
+        if let Some(value) = board.get_mut_from_pair(second) {
+            *value = 2;
+        }
+
+        assert_eq!(board.swap(first, second), Some(()));
+        assert_eq!(board.get_from_pair(first), Some(&2));
+        assert_eq!(board.get_from_pair(second), Some(&1));
+
+        assert_eq!(board.swap(first, first), Some(()));
+        assert_eq!(board.get_from_pair(first), Some(&2));
+
+        assert_eq!(board.swap(first, Pair::from_row_and_col(5, 0)), None);
+        assert_eq!(board.get_from_pair(first), Some(&2));
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut first = Board::<i32>::new(3, 2);
+        let second = Board::<i32>::new(3, 2);
+        assert_eq!(first, second);
+        assert_ne!(first, Board::<i32>::new(2, 3));
+
+        if let Some(value) =
+            first.get_mut_from_pair(Pair::from_row_and_col(1, 1))
+        {
+            *value = 1;
+        }
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_clone() {
+        let Some(maze) = Maze::from_backtracking(3, 3) else {
+            panic!("failed to generate maze");
+        };
+        let mut clone = maze.board.clone();
+        assert_eq!(clone, maze.board);
+
+        let pair = Pair::from_row_and_col(0, 0);
+        if let Some(tile) = clone.get_mut_from_pair(pair) {
+            *tile = Tile::Path;
+        }
+        assert_ne!(clone, maze.board);
+        assert_eq!(maze.board.get_from_pair(pair), Some(&Tile::Wall));
+    }
+
+    #[test]
+    fn test_rows() {
+        let mut board = Board::<i32>::new(3, 5);
+        assert_eq!(board.rows().count(), board.tile_height());
+        assert!(board.rows().all(|row| row.len() == board.tile_width()));
+
+        for row in board.rows_mut() {
+            row.fill(1);
+        }
+        assert!(board.rows().flatten().all(|value| *value == 1));
+    }
+
+    #[test]
+    fn test_perimeter_cells() {
+        let board = Board::<i32>::new(3, 3);
+        let perimeter = board.perimeter_cells().collect::<Vec<Pair>>();
+        assert_eq!(perimeter.len(), 8);
+        assert!(!perimeter.contains(&Pair::from_row_and_col(3, 3)));
+
+        assert_eq!(Board::<i32>::new(1, 4).perimeter_cells().count(), 4);
+
+        for (height, width) in [(1, 1), (5, 1), (1, 6), (2, 2), (6, 9)] {
+            let board = Board::<i32>::new(height, width);
+            let perimeter = board.perimeter_cells().collect::<Vec<Pair>>();
+            let expected = (0..height)
+                .flat_map(|row| (0..width).map(move |col| (row, col)))
+                .filter(|&(row, col)| {
+                    row == 0
+                        || col == 0
+                        || row + 1 == height
+                        || col + 1 == width
+                })
+                .filter_map(|(row, col)| {
+                    Board::<i32>::cell_position_to_pair(row, col)
+                })
+                .collect::<Vec<Pair>>();
+            assert_eq!(perimeter, expected);
+        }
+    }
+
+    #[test]
+    fn test_iter_cells() {
+        let board = Board::<i32>::new(2, 3);
+        let cells = board.iter_cells().collect::<Vec<(Pair, &i32)>>();
+
+        assert_eq!(cells.len(), 35);
+        assert_eq!(cells.first(), Some(&(Pair::from_row_and_col(0, 0), &0)));
+        assert_eq!(cells.get(8), Some(&(Pair::from_row_and_col(1, 1), &0)));
+        assert_eq!(cells.last(), Some(&(Pair::from_row_and_col(4, 6), &0)));
+    }
+
+    #[test]
+    fn test_iter_mut_cells() {
+        let mut board = Board::<i32>::new(2, 3);
+        for (pair, value) in board.iter_mut_cells() {
+            *value = pair.row.mul(10).add(pair.col);
+        }
+
+        assert_eq!(board.iter_mut_cells().count(), 35);
+        for (pair, value) in board.iter_cells() {
+            assert_eq!(*value, pair.row.mul(10).add(pair.col));
+        }
+    }
+
+    #[test]
+    fn test_count_where() {
+        let Ok(board) = Board::<Tile>::try_from(vec![
+            vec![Tile::Wall, Tile::Entry, Tile::Wall, Tile::Wall, Tile::Wall],
+            vec![Tile::Wall, Tile::Path, Tile::Path, Tile::Path, Tile::Wall],
+            vec![Tile::Wall, Tile::Wall, Tile::Wall, Tile::Wall, Tile::Wall],
+        ]) else {
+            panic!("failed to convert grid");
+        };
+
+        assert_eq!(board.count_where(|tile| *tile == Tile::Path), 3);
+        assert_eq!(board.count_where(Tile::is_passable), 4);
+    }
+
+    #[test]
+    fn test_get_wrapping() {
+        let mut board = Board::<i32>::new(2, 3);
+        *board.get_mut_wrapping(Pair::from_row_and_col(3, 7)) = 1;
+
+        assert_eq!(board.get_from_pair(Pair::from_row_and_col(3, 0)), Some(&1));
+        assert_eq!(*board.get_wrapping(Pair::from_row_and_col(-2, 0)), 1);
+        assert_eq!(board.get_from_pair(Pair::from_row_and_col(3, 7)), None);
+    }
+
+    /// Reads a value with the unchecked accessor once the pair is known to be
+    /// on the board.
+    fn checked_unchecked(board: &Board<Tile>, pair: Pair) -> Option<&Tile> {
+        board.get_from_pair(pair)?;
+        // SAFETY: `get_from_pair` just confirmed the pair is in bounds.
+        Some(unsafe { board.get_unchecked_from_pair(pair) })
+    }
+
+    #[test]
+    fn test_get_unchecked_from_pair() {
+        let Some(mut maze) = Maze::from_backtracking(3, 5) else {
+            panic!("failed to generate maze");
+        };
+
+        for (pair, tile) in maze.board.iter_cells() {
+            assert_eq!(checked_unchecked(&maze.board, pair), Some(tile));
+        }
+        assert_eq!(
+            checked_unchecked(&maze.board, Pair::from_row_and_col(7, 0)),
+            None
+        );
+
+        let pair = Pair::from_row_and_col(2, 2);
+        if maze.board.get_from_pair(pair).is_some() {
+            // SAFETY: the pair was just confirmed to be in bounds.
+            *unsafe { maze.board.get_unchecked_mut_from_pair(pair) } =
+                Tile::Item;
+        }
+        assert_eq!(maze.board.get_from_pair(pair), Some(&Tile::Item));
+    }
+
+    #[test]
+    fn test_subgrid() {
+        let Some(maze) = Maze::from_backtracking(4, 4) else {
+            panic!("failed to generate maze");
+        };
+        let Some(crop) = maze.board.subgrid(Pair::ZERO, 3, 3) else {
+            panic!("failed to crop board");
+        };
+
+        assert_eq!((crop.tile_height(), crop.tile_width()), (3, 3));
+        for (pair, tile) in crop.iter_cells() {
+            assert_eq!(maze.board.get_from_pair(pair), Some(tile));
+        }
+        assert!(maze
+            .board
+            .subgrid(Pair::from_row_and_col(7, 0), 3, 3)
+            .is_none());
+        for (height, width) in [(2, 4), (2, 3), (3, 4)] {
+            assert!(maze.board.subgrid(Pair::ZERO, height, width).is_none());
+        }
+    }
+
+    #[test]
+    fn test_paste() {
+        let mut board = Board::<i32>::new(3, 3);
+        let mut piece = Board::<i32>::new(1, 1);
+        if let Some(value) =
+            piece.get_mut_from_pair(Pair::from_row_and_col(1, 1))
+        {
+            *value = 1;
+        }
+
+        assert_eq!(board.paste(&piece, Pair::from_row_and_col(2, 4)), Some(()));
+        assert_eq!(board.get_from_pair(Pair::from_row_and_col(3, 5)), Some(&1));
+        assert_eq!(board.rows().flatten().sum::<i32>(), 1);
+
+        assert_eq!(board.paste(&piece, Pair::from_row_and_col(5, 0)), None);
+        assert_eq!(board.rows().flatten().sum::<i32>(), 1);
+    }
+
+    #[test]
+    fn test_try_from_grid() {
+        let Ok(board) = Board::<Tile>::try_from(vec![
+            vec![Tile::Wall, Tile::Entry, Tile::Wall],
+            vec![Tile::Wall, Tile::Path, Tile::Wall],
+            vec![Tile::Wall, Tile::Wall, Tile::Wall],
+        ]) else {
+            panic!("failed to convert grid");
+        };
+        assert_eq!((board.cell_height, board.cell_width), (1, 1));
+        assert_eq!(
+            board.get_from_pair(Pair::from_row_and_col(1, 1)),
+            Some(&Tile::Path)
+        );
+
+        let jagged = vec![
+            vec![Tile::Wall, Tile::Wall, Tile::Wall],
+            vec![Tile::Wall, Tile::Path],
+            vec![Tile::Wall, Tile::Wall, Tile::Wall],
+        ];
+        assert_eq!(
+            Board::<Tile>::try_from(jagged),
+            Err(MazeError::InvalidDimensions)
+        );
+    }
 }
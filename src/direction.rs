@@ -1,5 +1,14 @@
 //! A module detailing directions.
 
+use core::fmt::{Display, Formatter, Result};
+use core::iter;
+use core::ops::Mul;
+
+use rand::Rng;
+
+use crate::board::CELL_STEP;
+use crate::pair::Pair;
+
 /// The possible directions to move in the maze.
 #[derive(
     strum_macros::EnumIter, Clone, Copy, PartialEq, Eq, Hash, Debug, Default,
@@ -15,3 +24,234 @@ pub enum Direction {
     /// Going "left" (i.e. decrementing a row).
     Left,
 }
+
+impl Direction {
+    /// Every direction, in clockwise order from `Up`.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Turns 90 degrees clockwise.
+    ///
+    /// ### Returns
+    /// * The `Direction` to the right of this one.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::direction::Direction;
+    ///
+    /// assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    /// assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Gets the offset from a cell to its neighboring cell in this direction.
+    ///
+    /// ### Returns
+    /// * The `Pair` of this direction scaled by `CELL_STEP`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{direction::Direction, pair::Pair};
+    ///
+    /// assert_eq!(Direction::Right.cell_step(), Pair::from_row_and_col(0, 2));
+    /// assert_eq!(Direction::Up.cell_step(), Pair::from_row_and_col(-2, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cell_step(self) -> Pair {
+        CELL_STEP.mul(Pair::from(self))
+    }
+
+    /// Gets the bit standing for this direction in a direction mask.
+    ///
+    /// ### Returns
+    /// * `1` for `Up`, `2` for `Right`, `4` for `Down` and `8` for `Left`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::direction::Direction;
+    ///
+    /// assert_eq!(Direction::Up.bit(), 1);
+    /// assert_eq!(Direction::Left.bit(), 8);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn bit(self) -> u8 {
+        match self {
+            Self::Up => 1,
+            Self::Right => 2,
+            Self::Down => 4,
+            Self::Left => 8,
+        }
+    }
+
+    /// Iterates over every direction in clockwise order.
+    ///
+    /// ### Parameters
+    /// * `start`: The first `Direction` to yield.
+    ///
+    /// ### Returns
+    /// * An iterator over the four directions, beginning at `start`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::direction::Direction;
+    ///
+    /// let directions = Direction::clockwise_from(Direction::Up)
+    ///     .collect::<Vec<Direction>>();
+    /// assert_eq!(
+    ///     directions,
+    ///     [Direction::Up, Direction::Right, Direction::Down, Direction::Left]
+    /// );
+    /// ```
+    #[inline]
+    pub fn clockwise_from(start: Self) -> impl Iterator<Item = Self> {
+        iter::successors(Some(start), |direction| Some(direction.turn_right()))
+            .take(4)
+    }
+
+    /// Chooses a `Direction` uniformly at random.
+    ///
+    /// ### Parameters
+    /// * `rng`: The source of randomness.
+    ///
+    /// ### Returns
+    /// * One of the four directions.
+    #[inline]
+    pub fn random<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        match rng.gen_range(0..4_u8) {
+            0 => Self::Up,
+            1 => Self::Right,
+            2 => Self::Down,
+            _ => Self::Left,
+        }
+    }
+}
+
+/// Packs a set of directions into a bitmask.
+///
+/// ### Parameters
+/// * `directions`: The directions to set, where repeats are ignored.
+///
+/// ### Returns
+/// * The bits of every `Direction` in `directions` combined.
+#[inline]
+pub fn directions_to_mask<I>(directions: I) -> u8
+where
+    I: IntoIterator<Item = Direction>,
+{
+    directions
+        .into_iter()
+        .fold(0, |mask, direction| mask | direction.bit())
+}
+
+/// Unpacks a bitmask into the directions it contains.
+///
+/// ### Parameters
+/// * `mask`: The bitmask, where bits above `Left` are ignored.
+///
+/// ### Returns
+/// * The directions whose bits are set, in clockwise order from `Up`.
+#[inline]
+#[must_use]
+pub fn mask_to_directions(mask: u8) -> Vec<Direction> {
+    Direction::ALL
+        .into_iter()
+        .filter(|direction| mask & direction.bit() != 0)
+        .collect()
+}
+
+impl Display for Direction {
+    /// Writes the direction as an arrow.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::direction::Direction;
+    ///
+    /// assert_eq!(Direction::Up.to_string(), "↑");
+    /// assert_eq!(Direction::Left.to_string(), "←");
+    /// ```
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let arrow = match *self {
+            Self::Up => '↑',
+            Self::Right => '→',
+            Self::Down => '↓',
+            Self::Left => '←',
+        };
+        write!(formatter, "{arrow}")
+    }
+}
+
+#[cfg(test)]
+mod test_direction {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::direction::{directions_to_mask, mask_to_directions, Direction};
+
+    #[test]
+    fn test_random_is_deterministic() {
+        let sample = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..32)
+                .map(|_| Direction::random(&mut rng))
+                .collect::<Vec<Direction>>()
+        };
+
+        let directions = sample(7);
+        assert_eq!(directions, sample(7));
+        assert_ne!(directions, sample(8));
+
+        // pinned so that any change in how the rng is consumed is caught
+        assert_eq!(
+            directions[..12],
+            [
+                Direction::Right,
+                Direction::Up,
+                Direction::Up,
+                Direction::Right,
+                Direction::Up,
+                Direction::Up,
+                Direction::Down,
+                Direction::Down,
+                Direction::Down,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mask_round_trip() {
+        let directions = vec![Direction::Right, Direction::Left];
+        let mask = directions_to_mask(directions.clone());
+
+        assert_eq!(mask, 0b1010);
+        assert_eq!(mask_to_directions(mask), directions);
+        assert_eq!(
+            directions_to_mask([
+                Direction::Down,
+                Direction::Up,
+                Direction::Down
+            ]),
+            0b0101
+        );
+        assert_eq!(mask_to_directions(0xF0), vec![]);
+        assert_eq!(
+            mask_to_directions(directions_to_mask(Direction::ALL)),
+            Direction::ALL
+        );
+    }
+}
@@ -3,8 +3,42 @@
 use crate::pair::Pair;
 
 /// A connection between `Pairs`.
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Edge {
     /// The `Pairs` in the `Edge`.
     pub pairs: (Pair, Pair),
 }
+
+impl Edge {
+    /// Creates an `Edge` with its `Pairs` in a canonical order.
+    ///
+    /// The `Pair` with the smaller row, then the smaller column, comes
+    /// first, so an `Edge` hashes the same whichever way it was built.
+    ///
+    /// ### Parameters
+    /// * `first`: One end of the `Edge`.
+    /// * `second`: The other end of the `Edge`.
+    ///
+    /// ### Returns
+    /// * The normalized `Edge`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{edge::Edge, pair::Pair};
+    ///
+    /// let left = Pair::from_row_and_col(1, 1);
+    /// let right = Pair::from_row_and_col(1, 3);
+    /// assert_eq!(Edge::new(left, right), Edge::new(right, left));
+    /// assert_eq!(Edge::new(right, left).pairs, (left, right));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(first: Pair, second: Pair) -> Self {
+        let pairs = if first <= second {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        Self { pairs }
+    }
+}
@@ -0,0 +1,166 @@
+//! Editing the walls of an existing maze.
+
+use core::ops::Add;
+
+use strum::IntoEnumIterator;
+
+use crate::direction::Direction;
+use crate::maze::Maze;
+use crate::pair::Pair;
+use crate::tile::Tile;
+
+impl Maze {
+    /// Carves the wall between a cell and its neighbor.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to open the wall of.
+    /// * `direction`: The side of the cell the wall is on.
+    ///
+    /// ### Returns
+    /// * `Some(())` if both cells and the wall between them are now paths,
+    ///   or `None` if `cell` is not a cell or the neighbor is off the board.
+    #[inline]
+    pub fn open_wall(
+        &mut self,
+        cell: Pair,
+        direction: Direction,
+    ) -> Option<()> {
+        let neighbor = self.neighbor_cell(cell, direction)?;
+
+        for pair in [cell, cell.add(Pair::from(direction)), neighbor] {
+            *self.board.get_mut_from_pair(pair)? = Tile::Path;
+        }
+
+        Some(())
+    }
+
+    /// Rebuilds the wall between a cell and its neighbor.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to close the wall of.
+    /// * `direction`: The side of the cell the wall is on.
+    ///
+    /// ### Returns
+    /// * `Some(())` if the wall is now a wall, or `None` if `cell` is not a
+    ///   cell or the neighbor is off the board.
+    #[inline]
+    pub fn close_wall(
+        &mut self,
+        cell: Pair,
+        direction: Direction,
+    ) -> Option<()> {
+        self.neighbor_cell(cell, direction)?;
+        *self
+            .board
+            .get_mut_from_pair(cell.add(Pair::from(direction)))? = Tile::Wall;
+
+        Some(())
+    }
+
+    /// Connects two neighboring cells.
+    ///
+    /// ### Parameters
+    /// * `first`: One of the cells to connect.
+    /// * `second`: The other cell, `CELL_STEP` tiles away from `first`.
+    ///
+    /// ### Returns
+    /// * `Some(())` if both cells and the wall between them are now paths,
+    ///   or `None` if the pairs are not neighboring cells on the board.
+    #[inline]
+    pub fn carve_passage(&mut self, first: Pair, second: Pair) -> Option<()> {
+        let direction = Direction::iter()
+            .find(|direction| first.add(direction.cell_step()) == second)?;
+
+        self.open_wall(first, direction)
+    }
+
+    /// Gets the cell next to a cell if both are on the board.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to step from.
+    /// * `direction`: The way to step.
+    ///
+    /// ### Returns
+    /// * The neighboring cell, or `None` if either cell is off the board.
+    fn neighbor_cell(&self, cell: Pair, direction: Direction) -> Option<Pair> {
+        let neighbor = cell.add(direction.cell_step());
+        let on_board = |pair: Pair| {
+            pair.is_cell() && self.board.get_from_pair(pair).is_some()
+        };
+
+        (on_board(cell) && on_board(neighbor)).then_some(neighbor)
+    }
+}
+
+#[cfg(test)]
+mod test_edit {
+    use core::ops::Add;
+
+    use crate::{direction::Direction, maze::Maze, pair::Pair, tile::Tile};
+
+    #[test]
+    fn test_open_and_close_wall() {
+        let Some(mut maze) = Maze::from_backtracking(4, 4) else {
+            panic!("failed to generate maze");
+        };
+        let original = maze.board.clone();
+        let Some((cell, direction)) = maze
+            .board
+            .iter_cells()
+            .filter(|(pair, _)| pair.is_cell())
+            .flat_map(|(pair, _)| {
+                [Direction::Right, Direction::Down].map(|side| (pair, side))
+            })
+            .find(|(pair, side)| {
+                maze.board
+                    .get_from_pair(pair.add(side.cell_step()))
+                    .is_some()
+                    && maze.board.get_from_pair(pair.add(Pair::from(*side)))
+                        == Some(&Tile::Wall)
+            })
+        else {
+            panic!("expected a closed wall");
+        };
+        let wall = cell.add(Pair::from(direction));
+
+        assert_eq!(maze.open_wall(cell, direction), Some(()));
+        assert_eq!(maze.board.get_from_pair(wall), Some(&Tile::Path));
+        assert_eq!(maze.close_wall(cell, direction), Some(()));
+        assert_eq!(maze.board, original);
+
+        let corner = Pair::from_row_and_col(1, 1);
+        assert_eq!(maze.open_wall(corner, Direction::Up), None);
+        assert_eq!(maze.close_wall(corner, Direction::Left), None);
+        assert_eq!(maze.open_wall(Pair::ZERO, Direction::Down), None);
+        assert_eq!(maze.board, original);
+    }
+
+    #[test]
+    fn test_open_wall_carves_neighbor() {
+        let mut maze = Maze::new(1, 2);
+        let cell = Pair::from_row_and_col(1, 1);
+
+        assert_eq!(maze.open_wall(cell, Direction::Right), Some(()));
+        assert_eq!(maze.to_string(), "⬛⬛⬛⬛⬛\n⬛⬜⬜⬜⬛\n⬛⬛⬛⬛⬛\n");
+    }
+
+    #[test]
+    fn test_carve_passage() {
+        let mut maze = Maze::new(2, 2);
+        let first = Pair::from_row_and_col(1, 3);
+        let second = Pair::from_row_and_col(3, 3);
+
+        assert_eq!(maze.carve_passage(first, second), Some(()));
+        assert!(maze
+            .board
+            .get_from_pair(Pair::from_row_and_col(2, 3))
+            .is_some_and(Tile::is_passable));
+        assert!(maze.solve_dfs(second, first).is_some());
+
+        let diagonal = Pair::from_row_and_col(3, 1);
+        assert_eq!(maze.carve_passage(first, diagonal), None);
+        let outside = Pair::from_row_and_col(1, 5);
+        assert_eq!(maze.carve_passage(first, outside), None);
+        assert_eq!(maze.carve_passage(Pair::ZERO, first), None);
+    }
+}
@@ -0,0 +1,15 @@
+//! A module describing where the entrances of a maze are opened.
+
+use crate::pair::Pair;
+
+/// The number and arrangement of entrances to open in a maze.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum EntranceSpec {
+    /// Some number of entrances at random places on the perimeter.
+    Count(usize),
+    /// One entrance at each corner, opening up, right, down and left from the
+    /// top-left, top-right, bottom-right and bottom-left cells respectively.
+    Corners,
+    /// Entrances at exactly these perimeter wall tiles.
+    Explicit(Vec<Pair>),
+}
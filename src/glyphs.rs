@@ -0,0 +1,99 @@
+//! The characters each kind of tile is drawn with.
+
+use crate::tile::Tile;
+
+/// The characters used to draw each kind of tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyphs {
+    /// The character drawn for a wall.
+    pub wall: char,
+    /// The character drawn for a path.
+    pub path: char,
+    /// The character drawn for an entrance.
+    pub entry: char,
+    /// The character drawn for a cell on a solution path.
+    pub solution: char,
+    /// The character drawn for a cell holding an item.
+    pub item: char,
+    /// The character drawn for the start of a route.
+    pub start: char,
+    /// The character drawn for the end of a route.
+    pub end: char,
+}
+
+impl Glyphs {
+    /// Creates the default theme of colored squares.
+    ///
+    /// ### Returns
+    /// * The block `Glyphs`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::glyphs::Glyphs;
+    ///
+    /// assert_eq!(Glyphs::blocks().wall, '\u{2B1B}');
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn blocks() -> Self {
+        Self {
+            wall: '\u{2B1B}',
+            path: '\u{2B1C}',
+            entry: '\u{1F7E9}',
+            solution: '\u{1F7E5}',
+            item: '\u{1F7E8}',
+            start: '\u{1F7E6}',
+            end: '\u{1F7EA}',
+        }
+    }
+
+    /// Creates a theme using only ASCII characters.
+    ///
+    /// ### Returns
+    /// * The ASCII `Glyphs`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::glyphs::Glyphs;
+    ///
+    /// assert_eq!(Glyphs::ascii().wall, '#');
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ascii() -> Self {
+        Self {
+            wall: '#',
+            path: ' ',
+            entry: 'E',
+            solution: '.',
+            item: '*',
+            start: 'S',
+            end: 'F',
+        }
+    }
+
+    /// Gets the character for a tile.
+    ///
+    /// ### Parameters
+    /// * `tile`: The tile to draw.
+    ///
+    /// ### Returns
+    /// * The character representing the tile.
+    #[inline]
+    #[must_use]
+    pub const fn glyph(&self, tile: &Tile) -> char {
+        match *tile {
+            Tile::Wall => self.wall,
+            Tile::Path => self.path,
+            Tile::Entry => self.entry,
+            Tile::Item => self.item,
+        }
+    }
+}
+
+impl Default for Glyphs {
+    #[inline]
+    fn default() -> Self {
+        Self::blocks()
+    }
+}
@@ -1,10 +1,22 @@
 //! List of modules used in this crate.
 
+pub mod analysis;
+pub mod axis;
 pub mod board;
 pub mod direction;
 pub mod edge;
+pub mod edit;
+pub mod entrance_spec;
+pub mod glyphs;
 pub mod maze;
+pub mod maze_error;
 pub mod pair;
+pub mod render;
+pub mod solver;
 pub mod stack;
+#[cfg(test)]
+mod test_support;
 pub mod tile;
+pub mod transform;
 pub mod visit_status;
+pub mod wall_grid;
@@ -3,9 +3,12 @@
 use maze_generation::maze::Maze;
 
 fn main() {
-    let Some(maze) = Maze::from_backtracking(15, 20) else {
-        eprintln!("Failed to generate maze.");
-        return;
+    let maze = match Maze::try_from_backtracking(15, 20) {
+        Ok(maze) => maze,
+        Err(error) => {
+            eprintln!("Failed to generate maze: {error}.");
+            return;
+        }
     };
     println!("{maze:#?}");
 }
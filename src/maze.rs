@@ -1,22 +1,33 @@
 //! The maze and its generation algorithms
 
-use core::fmt::{Debug, Display, Formatter, Result};
-use std::collections::HashSet;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+use std::collections::{HashSet, VecDeque};
+use std::thread;
 
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    rngs::{StdRng, ThreadRng},
+    seq::SliceRandom,
+    thread_rng, Rng, SeedableRng,
+};
 use strum::IntoEnumIterator;
 
-use crate::board::{Board, CELL_STEP};
+use crate::axis::Axis;
+use crate::board::{Board, Cells, CELL_STEP};
 use crate::direction::Direction;
 use crate::edge::Edge;
+use crate::entrance_spec::EntranceSpec;
+use crate::maze_error::MazeError;
 use crate::pair::{Pair, Perimeter};
 use crate::stack::Stack;
 use crate::tile::Tile;
 use crate::visit_status::VisitStatus;
 use core::convert::From;
-use core::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Range, Sub};
 
 /// A maze generated by some algorithm.
+#[derive(PartialEq, Eq, Hash)]
 pub struct Maze {
     /// The grid of cells
     pub board: Board<Tile>,
@@ -24,28 +35,267 @@ pub struct Maze {
 
 impl Display for Maze {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
-        let mut result = String::new();
-
-        for row in &self.board.grid {
-            for cell in row {
-                result.push_str(cell.to_string().as_str());
-            }
-            result.push('\n');
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.row_strings() {
+            writeln!(formatter, "{row}")?;
         }
 
-        write!(formatter, "{result}")
+        Ok(())
+    }
+}
+
+impl<'maze> IntoIterator for &'maze Maze {
+    type Item = (Pair, &'maze Tile);
+    type IntoIter = Cells<'maze, Tile>;
+
+    /// Iterates over every tile in the maze along with its `Pair`.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.board.iter_cells()
     }
 }
 
 impl Debug for Maze {
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         write!(formatter, "{self}")
     }
 }
 
+impl FromStr for Maze {
+    type Err = MazeError;
+
+    /// Parses a maze drawn with ASCII or block glyphs, one line per row.
+    ///
+    /// ### Returns
+    /// * The maze, or the `MazeError` from `validate` if it is malformed.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::maze::Maze;
+    ///
+    /// let maze = "#E#\n# #\n#E#\n".parse::<Maze>();
+    /// assert!(maze.is_ok());
+    /// assert!("###\n# #\n###\n".parse::<Maze>().is_err());
+    /// ```
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let grid = text
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|glyph| {
+                        Tile::from_char(glyph)
+                            .ok_or(MazeError::UnknownGlyph(glyph))
+                    })
+                    .collect::<Result<Vec<Tile>, MazeError>>()
+            })
+            .collect::<Result<Vec<Vec<Tile>>, MazeError>>()?;
+
+        let maze = Self {
+            board: Board::try_from(grid)?,
+        };
+        maze.validate()?;
+        Ok(maze)
+    }
+}
+
 impl Maze {
+    /// Creates a maze with every tile set to a wall.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    ///
+    /// ### Returns
+    /// * A maze with no paths or entrances.
+    #[inline]
+    #[must_use]
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            board: Board::<Tile>::new(height, width),
+        }
+    }
+
+    /// Gets the number of cells in the maze.
+    ///
+    /// ### Returns
+    /// * The number of cell rows and cell columns, in that order.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::maze::Maze;
+    ///
+    /// let maze = Maze::from_backtracking(20, 20).unwrap_or(Maze::new(20, 20));
+    /// assert_eq!(maze.dimensions(), (20, 20));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn dimensions(&self) -> (usize, usize) {
+        (self.board.cell_height, self.board.cell_width)
+    }
+
+    /// Gets the tile of a cell from its logical position.
+    ///
+    /// ### Parameters
+    /// * `cell_row`: The row of the cell, counted in cells.
+    /// * `cell_col`: The column of the cell, counted in cells.
+    ///
+    /// ### Returns
+    /// * The tile of the cell, or `None` if the cell is outside the maze.
+    #[inline]
+    #[must_use]
+    pub fn get_tile(&self, cell_row: usize, cell_col: usize) -> Option<&Tile> {
+        if cell_row >= self.board.cell_height
+            || cell_col >= self.board.cell_width
+        {
+            return None;
+        }
+
+        self.board
+            .get_from_pair(Board::<Tile>::cell_position_to_pair(
+                cell_row, cell_col,
+            )?)
+    }
+
+    /// Compares the tiles of two mazes.
+    ///
+    /// ### Parameters
+    /// * `other`: The maze to compare against.
+    ///
+    /// ### Returns
+    /// * The `Pair` of each differing tile along with this maze's tile and
+    ///   `other`'s tile, in row-major order, or `None` if the mazes have
+    ///   different dimensions.
+    #[inline]
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Option<Vec<(Pair, Tile, Tile)>> {
+        if self.dimensions() != other.dimensions() {
+            return None;
+        }
+
+        Some(
+            self.board
+                .iter_cells()
+                .zip(other.board.iter_cells())
+                .filter(|((_, tile), (_, other_tile))| tile != other_tile)
+                .map(|((pair, tile), (_, other_tile))| {
+                    (pair, tile.clone(), other_tile.clone())
+                })
+                .collect(),
+        )
+    }
+
+    /// Checks the structural invariants of the maze.
+    ///
+    /// A valid maze has `2n + 1` tiles per row and column, at least one
+    /// entrance, every cell reachable from an entrance, and passable tiles
+    /// only on cells, between cells, or as entrances on the perimeter.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if the maze is valid, otherwise the first `MazeError` found.
+    #[inline]
+    pub fn validate(&self) -> Result<(), MazeError> {
+        let tile_height = self.board.tile_height();
+        let tile_width = self.board.tile_width();
+        if self.board.grid.len() != tile_height
+            || self.board.rows().any(|row| row.len() != tile_width)
+        {
+            return Err(MazeError::InvalidDimensions);
+        }
+
+        let mut entries = Vec::<Pair>::new();
+        for (pair, tile) in self {
+            let is_last = |index: i32, length: usize| {
+                usize::try_from(index).is_ok_and(|index| index.add(1) == length)
+            };
+            let on_perimeter = pair.row == 0
+                || pair.col == 0
+                || is_last(pair.row, tile_height)
+                || is_last(pair.col, tile_width);
+            let on_corner = pair.row.rem_euclid(CELL_STEP) == 0
+                && pair.col.rem_euclid(CELL_STEP) == 0;
+            let misplaced = match *tile {
+                Tile::Wall => false,
+                Tile::Path | Tile::Item => on_perimeter || on_corner,
+                Tile::Entry => !on_perimeter || on_corner,
+            };
+            if misplaced {
+                return Err(MazeError::MisplacedPassage(pair));
+            }
+
+            if *tile == Tile::Entry {
+                entries.push(pair);
+            }
+        }
+
+        if entries.is_empty() {
+            return Err(MazeError::MissingEntry);
+        }
+
+        let reachable = self.reachable_tiles(&entries);
+        for row in 0..self.board.cell_height {
+            for col in 0..self.board.cell_width {
+                let Some(pair) = Board::<Tile>::cell_position_to_pair(row, col)
+                else {
+                    return Err(MazeError::InvalidDimensions);
+                };
+                if !reachable
+                    .get_from_pair(pair)
+                    .is_some_and(VisitStatus::is_visited)
+                {
+                    return Err(MazeError::UnreachableCell(pair));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds every tile reachable from some starting tiles.
+    ///
+    /// ### Parameters
+    /// * `starts`: The passable tiles to search from.
+    ///
+    /// ### Returns
+    /// * A `Board` marking each reachable tile as visited.
+    fn reachable_tiles(&self, starts: &[Pair]) -> Board<VisitStatus> {
+        let mut visited = Board::<VisitStatus>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        let mut queue = VecDeque::<Pair>::new();
+
+        for start in starts {
+            if let Some(status) = visited.get_mut_from_pair(*start) {
+                *status = VisitStatus::Visited;
+                queue.push_back(*start);
+            }
+        }
+
+        while let Some(pair) = queue.pop_front() {
+            for direction in Direction::iter() {
+                let neighbor = pair.add(Pair::from(direction));
+                if !self
+                    .board
+                    .get_from_pair(neighbor)
+                    .is_some_and(Tile::is_passable)
+                {
+                    continue;
+                }
+
+                if let Some(status) = visited.get_mut_from_pair(neighbor) {
+                    if *status == VisitStatus::Unvisited {
+                        *status = VisitStatus::Visited;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        visited
+    }
+
     /// Uses Kruskal's algorithm to randomly generate a maze.
     ///
     /// ### Parameters
@@ -57,11 +307,72 @@ impl Maze {
     #[inline]
     #[must_use]
     pub fn from_kruskals(height: usize, width: usize) -> Option<Self> {
-        let mut board = Board::<Tile>::new(height, width);
+        Self::check_dimensions(height, width).ok()?;
+        let board = Board::<Tile>::new(height, width);
 
         Some(Self { board })
     }
 
+    /// Generates a maze by carving a given set of edges.
+    ///
+    /// Each `Edge` holds the tile `Pairs` of two adjacent cells, in either
+    /// order. The maze starts as all walls, and each edge carves both of its
+    /// cells and the wall between them. No entrances are added.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `edges`: The passages to carve.
+    ///
+    /// ### Returns
+    /// * The carved maze, or `None` if any edge does not join two adjacent
+    ///   cells on the board.
+    #[inline]
+    #[must_use]
+    pub fn from_edges(
+        height: usize,
+        width: usize,
+        edges: &HashSet<Edge>,
+    ) -> Option<Self> {
+        let mut maze = Self::new(height, width);
+
+        for edge in edges {
+            let (first, second) = edge.pairs;
+            maze.carve_passage(first, second)?;
+        }
+
+        Some(maze)
+    }
+
+    /// Collects the passages carved between adjacent cells.
+    ///
+    /// ### Returns
+    /// * A normalized `Edge` for each open wall between two cells.
+    #[inline]
+    #[must_use]
+    pub fn passages(&self) -> HashSet<Edge> {
+        self.board
+            .iter_cells()
+            .filter(|(pair, tile)| pair.is_cell() && tile.is_passable())
+            .flat_map(|(pair, _)| {
+                [Direction::Right, Direction::Down]
+                    .into_iter()
+                    .filter(move |direction| {
+                        self.board
+                            .get_from_pair(pair.add(Pair::from(*direction)))
+                            .is_some_and(Tile::is_passable)
+                    })
+                    .map(move |direction| pair.add(direction.cell_step()))
+                    .filter(|neighbor| {
+                        self.board
+                            .get_from_pair(*neighbor)
+                            .is_some_and(Tile::is_passable)
+                    })
+                    .map(move |neighbor| Edge::new(pair, neighbor))
+            })
+            .collect()
+    }
+
     /// Initializes the edges in a `Maze`.
     ///
     /// ### Parameters
@@ -69,8 +380,9 @@ impl Maze {
     ///
     /// ### Returns
     /// * The complete set of edges in the maze.
-    fn init_edges(board: &Board<Tile>) -> HashSet<Edge> {
-        let mut result = HashSet::<Edge>::new();
+    #[expect(dead_code, reason = "not yet used by `from_kruskals`")]
+    fn init_edges(_board: &Board<Tile>) -> HashSet<Edge> {
+        let result = HashSet::<Edge>::new();
 
         // loop over all cells and add their possible edges to the cell
         // TODO: how to handle order-sensitivity? Current Edge implementation is an ordered tuple
@@ -88,6 +400,7 @@ impl Maze {
     ///
     /// ### Returns
     /// * A `HashSet` of `Edges`.
+    #[expect(dead_code, reason = "not yet used by `from_kruskals`")]
     fn get_valid_edges_for_pair<T>(
         pair: Pair,
         board: &Board<T>,
@@ -98,8 +411,7 @@ impl Maze {
         Self::get_possible_directions::<T>(pair, board)
             .into_iter()
             .map(|direction| {
-                let pairs =
-                    (pair, pair.add(CELL_STEP.mul(Pair::from(direction))));
+                let pairs = (pair, pair.add(direction.cell_step()));
                 Edge { pairs }
             })
             .collect::<HashSet<Edge>>()
@@ -116,228 +428,1155 @@ impl Maze {
     #[inline]
     #[must_use]
     pub fn from_backtracking(height: usize, width: usize) -> Option<Self> {
-        let mut board = Board::<Tile>::new(height, width);
-
-        let mut visited = Board::<VisitStatus>::new(height, width);
-        let start = Self::choose_perimeter_pair(&board)?;
-        Self::add_maze_entry(start, &mut board);
-
-        let mut visited_stack: Stack<Pair> = Stack::new();
-
-        visited_stack.push(start.pair);
-        let _: bool =
-            Self::visit_and_mark_as_path(&mut board, &mut visited, start.pair)?;
-
-        while !visited_stack.empty() {
-            let popped_pair = visited_stack.top()?;
-
-            let Some(direction) =
-                Self::choose_random_unvisited_direction(popped_pair, &visited)
-            else {
-                visited_stack.pop();
-                let _: bool = Self::visit_and_mark_as_path(
-                    &mut board,
-                    &mut visited,
-                    popped_pair,
-                )?;
-                continue;
-            };
-
-            let new_pair =
-                popped_pair.add(CELL_STEP.mul(Pair::from(direction)));
-            visited_stack.push(new_pair);
-            let _: bool = Self::visit_and_mark_as_path(
-                &mut board,
-                &mut visited,
-                new_pair,
-            )?;
-
-            // the in-between cell should be a wall, which we can remove
-            let in_between_pair = popped_pair.add(Pair::from(direction));
-            let _: bool = Self::visit_and_mark_as_path(
-                &mut board,
-                &mut visited,
-                in_between_pair,
-            )?;
-        }
-
-        let end = Self::choose_perimeter_pair(&board)?;
-        let _: bool =
-            Self::visit_and_mark_as_path(&mut board, &mut visited, end.pair)?;
-        Self::add_maze_entry(end, &mut board);
-
-        Some(Self { board })
+        Self::try_from_backtracking(height, width).ok()
     }
 
-    /// Updates the board and its visitation status against some pair.
+    /// Uses a backtracking algorithm to randomly generate a maze.
     ///
     /// ### Parameters
-    /// * `board`: The board of the maze.
-    /// * `visited`: The visitation status of each tile in the maze.
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
     ///
     /// ### Returns
-    /// * `true` if the update succeeded, otherwise `None` if there was an indexing issue.
-    fn visit_and_mark_as_path(
-        board: &mut Board<Tile>,
-        visited: &mut Board<VisitStatus>,
-        pair: Pair,
-    ) -> Option<bool> {
-        *board.get_mut_from_pair(pair)? = Tile::Path;
-        *visited.get_mut_from_pair(pair)? = VisitStatus::Visited;
-
-        Some(true)
+    /// * The fully generated maze, or the `MazeError` that stopped generation.
+    #[inline]
+    pub fn try_from_backtracking(
+        height: usize,
+        width: usize,
+    ) -> Result<Self, MazeError> {
+        Self::try_from_backtracking_with_rng(height, width, &mut thread_rng())
     }
 
-    /// Adds an entry point to the maze.
+    /// Uses a backtracking algorithm to generate a maze from some `Rng`.
     ///
     /// ### Parameters
-    /// * `pair`: The `Pair` adjacent to the perimeter of the board.
-    /// * `board`: The board of `Tiles` to update.
-    fn add_maze_entry(perimeter_tile: Perimeter, board: &mut Board<Tile>) {
-        let Some(direction) = Direction::iter().find(|direction| {
-            let possible_perimeter_tile = board.get_from_pair(
-                perimeter_tile
-                    .pair
-                    .add(CELL_STEP.mul(Pair::from(*direction))),
-            );
-            possible_perimeter_tile.is_none()
-        }) else {
-            return;
-        };
-
-        let Some(cell) = board
-            .get_mut_from_pair(perimeter_tile.pair.add(Pair::from(direction)))
-        else {
-            return;
-        };
-
-        *cell = Tile::Entry;
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `rng`: The source of every random choice made during generation.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze.
+    #[inline]
+    pub fn from_backtracking_with_rng<R>(
+        height: usize,
+        width: usize,
+        rng: &mut R,
+    ) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        Self::try_from_backtracking_with_rng(height, width, rng).ok()
     }
 
-    /// Chooses a `Pair` from the perimeter of the maze.
+    /// Uses a backtracking algorithm to generate a maze seeded by a name.
+    ///
+    /// The name is hashed into the seed with FNV-1a, which is fixed rather
+    /// than tied to the standard library's hasher, so the same name produces
+    /// the same maze on every toolchain.
     ///
     /// ### Parameters
-    /// * `board`: A reference to the board to get a perimeter cell from.
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `name`: The human-readable name of the maze.
     ///
     /// ### Returns
-    /// * An optional pair.
-    fn choose_perimeter_pair(board: &Board<Tile>) -> Option<Perimeter> {
-        let side = Direction::iter()
-            .collect::<Vec<Direction>>()
-            .choose(&mut thread_rng())
-            .copied()
-            .unwrap_or_default();
-
-        let unsigned_to_signed_cell = |value: usize| {
-            i32::try_from(Board::<Tile>::cell_position_to_index(value)).ok()
-        };
+    /// * An optional fully generated maze.
+    #[inline]
+    #[must_use]
+    pub fn from_name(height: usize, width: usize, name: &str) -> Option<Self> {
+        Self::from_backtracking_with_rng(
+            height,
+            width,
+            &mut StdRng::seed_from_u64(Self::name_seed(name)),
+        )
+    }
 
-        let pick_random_cell = |max: usize| {
-            unsigned_to_signed_cell(thread_rng().gen_range(0..max))
-        };
+    /// Hashes a name into a seed with 64-bit FNV-1a.
+    ///
+    /// ### Parameters
+    /// * `name`: The name to hash.
+    ///
+    /// ### Returns
+    /// * The FNV-1a hash of the name's UTF-8 bytes.
+    fn name_seed(name: &str) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
 
-        match side {
-            Direction::Down => {
-                let row = unsigned_to_signed_cell(board.cell_height.sub(1))?;
-                let col = pick_random_cell(board.cell_width)?;
-                Some(Perimeter {
-                    pair: Pair { row, col },
-                })
-            }
-            Direction::Left => {
-                let row = pick_random_cell(board.cell_height)?;
-                let col = unsigned_to_signed_cell(0)?;
-                Some(Perimeter {
-                    pair: Pair { row, col },
-                })
-            }
-            Direction::Up => {
-                let row = unsigned_to_signed_cell(0)?;
-                let col = pick_random_cell(board.cell_width)?;
-                Some(Perimeter {
-                    pair: Pair { row, col },
-                })
-            }
-            Direction::Right => {
-                let row = pick_random_cell(board.cell_height)?;
-                let col = unsigned_to_signed_cell(board.cell_width.sub(1))?;
-                Some(Perimeter {
-                    pair: Pair { row, col },
-                })
-            }
-        }
+        name.bytes().fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
     }
 
-    /// Choosese a random unvisited direction.
+    /// Uses a backtracking algorithm to generate a maze from some `Rng`.
+    ///
+    /// Zero rows or columns are rejected before the `Rng` is ever used.
     ///
     /// ### Parameters
-    /// * `pair`: A `Pair` to access a `Board` with.
-    /// * `visited`: The `Board` of visitation status.
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `rng`: The source of every random choice made during generation.
     ///
     /// ### Returns
-    /// * An optional direction.
+    /// * The fully generated maze, or the `MazeError` that stopped generation.
     #[inline]
-    #[must_use]
-    pub fn choose_random_unvisited_direction(
-        pair: Pair,
-        visited: &Board<VisitStatus>,
-    ) -> Option<Direction> {
-        let direction_choices = Self::get_unvisited_directions(pair, visited);
-        direction_choices
-            .into_iter()
-            .collect::<Vec<Direction>>()
-            .choose(&mut thread_rng())
-            .copied()
+    pub fn try_from_backtracking_with_rng<R>(
+        height: usize,
+        width: usize,
+        rng: &mut R,
+    ) -> Result<Self, MazeError>
+    where
+        R: Rng + ?Sized,
+    {
+        Self::backtrack_between_sides(
+            height,
+            width,
+            &Direction::ALL,
+            &Direction::ALL,
+            rng,
+        )
     }
 
-    /// Get all valid directions for the current cell.
+    /// Uses a backtracking algorithm to generate a maze into existing boards,
+    /// reusing their allocations.
+    ///
+    /// Both boards are reset before carving, and the maze takes the
+    /// dimensions of `board`.
     ///
     /// ### Parameters
-    /// * `pair`: The `Pair` to check valid directions for.
-    /// * `board`: The `Board` to check the `Pair` against.
+    /// * `board`: The board to carve the maze into.
+    /// * `visited`: A board of the same size to track visited cells with.
+    /// * `rng`: The source of every random choice made during generation.
     ///
     /// ### Returns
-    /// * A `HashSet` of `Directions`.
-    fn get_possible_directions<T>(
-        pair: Pair,
-        board: &Board<T>,
-    ) -> HashSet<Direction>
+    /// * `Some(())` if a maze was carved, or `None` if the boards differ in
+    ///   size or have no cells.
+    #[inline]
+    pub fn regenerate_into<R>(
+        board: &mut Board<Tile>,
+        visited: &mut Board<VisitStatus>,
+        rng: &mut R,
+    ) -> Option<()>
     where
-        T: Sized,
+        R: Rng + ?Sized,
     {
-        Direction::iter()
-            .filter(|direction| {
-                board
-                    .get_from_pair(
-                        pair.add(CELL_STEP.mul(Pair::from(*direction))),
-                    )
-                    .is_some()
-            })
-            .collect::<HashSet<Direction>>()
+        Self::check_dimensions(board.cell_height, board.cell_width).ok()?;
+        if (board.cell_height, board.cell_width)
+            != (visited.cell_height, visited.cell_width)
+        {
+            return None;
+        }
+
+        for row in board.rows_mut() {
+            row.fill(Tile::Wall);
+        }
+        for row in visited.rows_mut() {
+            row.fill(VisitStatus::Unvisited);
+        }
+
+        Self::backtrack_into(
+            board,
+            visited,
+            &Direction::ALL,
+            &Direction::ALL,
+            0.0,
+            rng,
+        )
+        .ok()
     }
 
-    /// Gets unvisited directions.
+    /// Uses a backtracking algorithm to generate a maze with its entrance and
+    /// exit on chosen sides.
     ///
     /// ### Parameters
-    /// * `pair`: A `Pair` to access a `Board` with.
-    /// * `visited`: The `Board` of visitation status.
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `entry_side`: The side of the maze to put the entrance on.
+    /// * `exit_side`: The side of the maze to put the exit on.
     ///
     /// ### Returns
-    /// * A set of `Direction` enums.
+    /// * An optional fully generated maze, which is `None` if both openings
+    ///   are on the same side and it is too short to fit two of them.
     #[inline]
     #[must_use]
-    pub fn get_unvisited_directions(
-        pair: Pair,
-        visited: &Board<VisitStatus>,
-    ) -> HashSet<Direction> {
-        Self::get_possible_directions::<VisitStatus>(pair, visited)
-            .into_iter()
-            .filter(|direction| {
-                let Some(visit_status_of_new_pair) = visited.get_from_pair(
-                    pair.add(CELL_STEP.mul(Pair::from(*direction))),
-                ) else {
-                    return false;
-                };
+    pub fn from_backtracking_sides(
+        height: usize,
+        width: usize,
+        entry_side: Direction,
+        exit_side: Direction,
+    ) -> Option<Self> {
+        Self::backtrack_between_sides(
+            height,
+            width,
+            &[entry_side],
+            &[exit_side],
+            &mut thread_rng(),
+        )
+        .ok()
+    }
+
+    /// Uses a backtracking algorithm to generate a maze with its entrance and
+    /// exit on randomly weighted sides.
+    ///
+    /// Every closed outer wall next to a perimeter cell is a candidate
+    /// opening, weighted by the weight of the side it is on, so a side is
+    /// picked in proportion to its weight and its length.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `weights`: The relative chance of each side, in `Direction` order
+    ///   (up, right, down, left). A side with zero weight is never used.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze, which is `None` only if no weight
+    ///   is positive, any weight is negative or not finite, or the positively
+    ///   weighted sides have fewer than two openings between them.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_side_weights(
+        height: usize,
+        width: usize,
+        weights: [f64; 4],
+    ) -> Option<Self> {
+        Self::check_dimensions(height, width).ok()?;
+        if weights
+            .iter()
+            .any(|weight| !weight.is_finite() || *weight < 0.0)
+        {
+            return None;
+        }
+
+        let mut board = Board::<Tile>::new(height, width);
+        let mut visited = Board::<VisitStatus>::new(height, width);
+        if Self::weighted_openings(&board, weights).len() < 2 {
+            return None;
+        }
+
+        let rng = &mut thread_rng();
+        let (start, entry_side) =
+            Self::choose_weighted_opening(&board, weights, rng)?;
+        Self::add_maze_entry(start, &[entry_side], &mut board);
+        Self::carve_passages(&mut board, &mut visited, start.pair, rng).ok()?;
+
+        let (end, exit_side) =
+            Self::choose_weighted_opening(&board, weights, rng)?;
+        Self::add_maze_entry(end, &[exit_side], &mut board);
+
+        Some(Self { board })
+    }
+
+    /// Uses a backtracking algorithm biased towards long straight corridors.
+    ///
+    /// Whenever the move that reached a cell can be repeated, it is repeated
+    /// with probability `bias`; otherwise an unvisited direction is picked
+    /// uniformly as usual.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `bias`: The chance of carving straight on, clamped to `0.0..=1.0`,
+    ///   where `0.0` matches `from_backtracking`.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_straight_bias(
+        height: usize,
+        width: usize,
+        bias: f64,
+    ) -> Option<Self> {
+        Self::check_dimensions(height, width).ok()?;
+
+        let mut board = Board::<Tile>::new(height, width);
+        let mut visited = Board::<VisitStatus>::new(height, width);
+        Self::backtrack_into(
+            &mut board,
+            &mut visited,
+            &Direction::ALL,
+            &Direction::ALL,
+            bias,
+            &mut thread_rng(),
+        )
+        .ok()?;
+
+        Some(Self { board })
+    }
+
+    /// Uses a backtracking algorithm to generate a mirror-symmetric maze.
+    ///
+    /// One half is generated and reflected across the axis. When the number
+    /// of cells across the axis is even, a single passage through the middle
+    /// joins the halves. When it is odd, the middle line of cells belongs to
+    /// neither half and becomes one straight corridor, opened into each half
+    /// at a single mirrored cell, so the maze stays free of loops. The
+    /// entrance and exit mirror each other on the two outer sides.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `axis`: The line to reflect the maze across.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_symmetric(
+        height: usize,
+        width: usize,
+        axis: Axis,
+    ) -> Option<Self> {
+        Self::check_dimensions(height, width).ok()?;
+        let rng = &mut thread_rng();
+
+        let (half_height, half_width) = match axis {
+            Axis::Horizontal => (height.div(2), width),
+            Axis::Vertical => (height, width.div(2)),
+        };
+        let mut board = Board::<Tile>::new(height, width);
+        let last_row = i32::try_from(board.tile_height()).ok()?.sub(1);
+        let last_col = i32::try_from(board.tile_width()).ok()?.sub(1);
+        let mirror = |pair: Pair| match axis {
+            Axis::Horizontal => {
+                Pair::from_row_and_col(last_row.sub(pair.row), pair.col)
+            }
+            Axis::Vertical => {
+                Pair::from_row_and_col(pair.row, last_col.sub(pair.col))
+            }
+        };
+
+        if half_height > 0 && half_width > 0 {
+            let mut half = Board::<Tile>::new(half_height, half_width);
+            let mut visited =
+                Board::<VisitStatus>::new(half_height, half_width);
+            let start =
+                *half.perimeter_cells().collect::<Vec<Pair>>().choose(rng)?;
+            Self::carve_passages(&mut half, &mut visited, start, rng).ok()?;
+
+            for (pair, tile) in half.iter_cells() {
+                *board.get_mut_from_pair(pair)? = tile.clone();
+                *board.get_mut_from_pair(mirror(pair))? = tile.clone();
+            }
+        }
+
+        let (across, along) = match axis {
+            Axis::Horizontal => (height, width),
+            Axis::Vertical => (width, height),
+        };
+        let (middle, last_along) = match axis {
+            Axis::Horizontal => (last_row.div(2), last_col),
+            Axis::Vertical => (last_col.div(2), last_row),
+        };
+        // the tile `offset` tiles off the middle line and `index` along it
+        let on_line = |offset: i32, index: i32| match axis {
+            Axis::Horizontal => {
+                Pair::from_row_and_col(middle.add(offset), index)
+            }
+            Axis::Vertical => Pair::from_row_and_col(index, middle.add(offset)),
+        };
+        let pick_cell = |rng: &mut ThreadRng| {
+            i32::try_from(Board::<Tile>::cell_position_to_index(
+                rng.gen_range(0..along),
+            ))
+            .ok()
+        };
+
+        let link = pick_cell(rng)?;
+        if across.is_multiple_of(2) {
+            *board.get_mut_from_pair(on_line(0, link))? = Tile::Path;
+        } else {
+            // the middle line is its own mirror image, so it has to be a
+            // single corridor linked to each half at one mirrored cell;
+            // anything else would either leave it cut off or close a loop
+            for index in 1..last_along {
+                *board.get_mut_from_pair(on_line(0, index))? = Tile::Path;
+            }
+            if across > 1 {
+                *board.get_mut_from_pair(on_line(-1, link))? = Tile::Path;
+                *board.get_mut_from_pair(on_line(1, link))? = Tile::Path;
+            }
+        }
+
+        let cell = pick_cell(rng)?;
+        let entry = match axis {
+            Axis::Horizontal => Pair::from_row_and_col(0, cell),
+            Axis::Vertical => Pair::from_row_and_col(cell, 0),
+        };
+        *board.get_mut_from_pair(entry)? = Tile::Entry;
+        *board.get_mut_from_pair(mirror(entry))? = Tile::Entry;
+
+        Some(Self { board })
+    }
+
+    /// Uses a backtracking algorithm to generate a maze with some arrangement
+    /// of entrances.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `spec`: The entrances to open.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze, which is `None` if `spec` asks for
+    ///   no entrances, more entrances than the perimeter can fit, or lists a
+    ///   tile more than once or one that isn't a perimeter wall between a
+    ///   cell and the outside.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_entrances(
+        height: usize,
+        width: usize,
+        spec: EntranceSpec,
+    ) -> Option<Self> {
+        Self::check_dimensions(height, width).ok()?;
+        let rng = &mut thread_rng();
+
+        let mut board = Board::<Tile>::new(height, width);
+        let mut visited = Board::<VisitStatus>::new(height, width);
+        let start =
+            *board.perimeter_cells().collect::<Vec<Pair>>().choose(rng)?;
+        Self::carve_passages(&mut board, &mut visited, start, rng).ok()?;
+
+        let entries = match spec {
+            EntranceSpec::Count(0) => return None,
+            EntranceSpec::Count(count) => {
+                for _ in 0..count {
+                    let perimeter = Self::choose_perimeter_pair(
+                        &board,
+                        &Direction::ALL,
+                        rng,
+                    )?;
+                    Self::add_maze_entry(
+                        perimeter,
+                        &Direction::ALL,
+                        &mut board,
+                    );
+                }
+                return Some(Self { board });
+            }
+            EntranceSpec::Corners => [
+                (0, 0, Direction::Up),
+                (0, width.sub(1), Direction::Right),
+                (height.sub(1), width.sub(1), Direction::Down),
+                (height.sub(1), 0, Direction::Left),
+            ]
+            .into_iter()
+            .map(|(row, col, side)| {
+                Some(
+                    Board::<Tile>::cell_position_to_pair(row, col)?
+                        .add(Pair::from(side)),
+                )
+            })
+            .collect::<Option<Vec<Pair>>>()?,
+            EntranceSpec::Explicit(entries) => entries,
+        };
+        if entries.is_empty() {
+            return None;
+        }
+
+        let last_row = i32::try_from(board.tile_height()).ok()?.sub(1);
+        let last_col = i32::try_from(board.tile_width()).ok()?.sub(1);
+        for entry in &entries {
+            // an entrance must sit in the outer wall right next to a cell
+            let on_side = [
+                (entry.row == 0, Direction::Down),
+                (entry.row == last_row, Direction::Up),
+                (entry.col == 0, Direction::Right),
+                (entry.col == last_col, Direction::Left),
+            ]
+            .into_iter()
+            .any(|(on_edge, inward)| {
+                let cell = entry.add(Pair::from(inward));
+                on_edge && cell.is_cell() && board.get_from_pair(cell).is_some()
+            });
+            let tile = board.get_mut_from_pair(*entry)?;
+            if !on_side || *tile == Tile::Entry {
+                return None;
+            }
+            *tile = Tile::Entry;
+        }
+
+        Some(Self { board })
+    }
+
+    /// Uses a backtracking algorithm to generate a maze on a torus.
+    ///
+    /// The left and right edges are joined, as are the top and bottom, so a
+    /// passage may leave one side of the board and come back in on the
+    /// other. Such a passage opens the perimeter wall tile on both sides.
+    /// The torus has no outside, so no entrances are added.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_toroidal(
+        height: usize,
+        width: usize,
+    ) -> Option<Self> {
+        Self::check_dimensions(height, width).ok()?;
+        let rng = &mut thread_rng();
+
+        let mut board = Board::<Tile>::new(height, width);
+        let mut visited = Board::<VisitStatus>::new(height, width);
+        let start = Board::<Tile>::cell_position_to_pair(
+            rng.gen_range(0..height),
+            rng.gen_range(0..width),
+        )?;
+        *board.get_mut_from_pair(start)? = Tile::Path;
+        *visited.get_mut_from_pair(start)? = VisitStatus::Visited;
+        let mut stack = Stack::from(start);
+
+        while let Some(cell) = stack.top() {
+            let unvisited = Direction::iter()
+                .filter_map(|direction| {
+                    let neighbor = Self::wrap_cell(
+                        &board,
+                        cell.add(direction.cell_step()),
+                    )?;
+                    (*visited.get_from_pair(neighbor)?
+                        == VisitStatus::Unvisited)
+                        .then_some((direction, neighbor))
+                })
+                .collect::<Vec<(Direction, Pair)>>();
+            let Some(&(direction, neighbor)) = unvisited.choose(rng) else {
+                stack.pop();
+                continue;
+            };
+
+            // across a seam, the wall shows up on both edges of the board
+            for pair in [
+                cell.add(Pair::from(direction)),
+                neighbor.sub(Pair::from(direction)),
+                neighbor,
+            ] {
+                *board.get_mut_from_pair(pair)? = Tile::Path;
+            }
+            *visited.get_mut_from_pair(neighbor)? = VisitStatus::Visited;
+            stack.push(neighbor);
+        }
+
+        Some(Self { board })
+    }
+
+    /// Wraps a cell onto the board as if its opposite edges were joined.
+    ///
+    /// ### Parameters
+    /// * `board`: The board to wrap onto.
+    /// * `cell`: The tile `Pair` of a cell, which may be off the board.
+    ///
+    /// ### Returns
+    /// * The tile `Pair` of the cell on the board, or `None` if the
+    ///   dimensions overflow.
+    fn wrap_cell(board: &Board<Tile>, cell: Pair) -> Option<Pair> {
+        let wrap = |index: i32, cells: usize| {
+            let span = i32::try_from(cells.mul(CELL_STEP as usize)).ok()?;
+            Some(index.sub(1).rem_euclid(span).add(1))
+        };
+
+        Some(Pair::from_row_and_col(
+            wrap(cell.row, board.cell_height)?,
+            wrap(cell.col, board.cell_width)?,
+        ))
+    }
+
+    /// Uses a backtracking algorithm on separate threads to generate a maze
+    /// from a seed.
+    ///
+    /// The board is split into up to four quadrants, each carved on its own
+    /// thread from a seed drawn from the master seed. The quadrants are then
+    /// joined by one passage between the top two, one between the left two
+    /// and one between the right two, so the result stays a perfect maze.
+    /// Threads never share an `Rng`, so the maze depends only on the seed.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `seed`: The seed every random choice is derived from.
+    ///
+    /// ### Returns
+    /// * An optional fully generated maze.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_parallel(
+        height: usize,
+        width: usize,
+        seed: u64,
+    ) -> Option<Self> {
+        Self::check_dimensions(height, width).ok()?;
+        let rng = &mut StdRng::seed_from_u64(seed);
+
+        let (top, left) = (height.add(1).div(2), width.add(1).div(2));
+        let quadrants = [
+            (0, 0, top, left),
+            (0, left, top, width.sub(left)),
+            (top, 0, height.sub(top), left),
+            (top, left, height.sub(top), width.sub(left)),
+        ]
+        .map(|quadrant| (quadrant, rng.gen::<u64>()));
+
+        let pieces = thread::scope(|scope| {
+            quadrants
+                .into_iter()
+                .filter(|((_, _, rows, cols), _)| *rows > 0 && *cols > 0)
+                .map(|((row, col, rows, cols), quadrant_seed)| {
+                    let handle = scope.spawn(move || {
+                        let mut piece = Board::<Tile>::new(rows, cols);
+                        let mut visited = Board::<VisitStatus>::new(rows, cols);
+                        let start = Board::<Tile>::cell_position_to_pair(0, 0)?;
+                        Self::carve_passages(
+                            &mut piece,
+                            &mut visited,
+                            start,
+                            &mut StdRng::seed_from_u64(quadrant_seed),
+                        )
+                        .ok()?;
+                        Some(piece)
+                    });
+                    (row, col, handle)
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|(row, col, handle)| {
+                    Some((row, col, handle.join().ok()??))
+                })
+                .collect::<Option<Vec<_>>>()
+        })?;
+
+        let mut board = Board::<Tile>::new(height, width);
+        for (row, col, piece) in &pieces {
+            let at = Pair::from_row_and_col(
+                i32::try_from(row.mul(CELL_STEP as usize)).ok()?,
+                i32::try_from(col.mul(CELL_STEP as usize)).ok()?,
+            );
+            board.paste(piece, at)?;
+        }
+
+        let mut open_wall = |rows: Range<usize>, cols: Range<usize>, side| {
+            let cell = Board::<Tile>::cell_position_to_pair(
+                rng.gen_range(rows),
+                rng.gen_range(cols),
+            )?;
+            *board.get_mut_from_pair(cell.add(Pair::from(side)))? = Tile::Path;
+            Some(())
+        };
+        if width > 1 {
+            open_wall(0..top, left.sub(1)..left, Direction::Right)?;
+        }
+        if height > 1 {
+            open_wall(top.sub(1)..top, 0..left, Direction::Down)?;
+            if width > 1 {
+                open_wall(top.sub(1)..top, left..width, Direction::Down)?;
+            }
+        }
+
+        for _ in 0..2 {
+            let perimeter =
+                Self::choose_perimeter_pair(&board, &Direction::ALL, rng)?;
+            Self::add_maze_entry(perimeter, &Direction::ALL, &mut board);
+        }
+
+        Some(Self { board })
+    }
+
+    /// Uses a backtracking algorithm to generate mazes until one has a long
+    /// enough solution.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `min_len`: The fewest steps the shortest path between the two
+    ///   entrances may take.
+    /// * `attempts`: The most mazes to generate before giving up.
+    ///
+    /// ### Returns
+    /// * The first maze whose shortest solution is at least `min_len` steps,
+    ///   or `None` if none of the attempts qualified.
+    #[inline]
+    #[must_use]
+    pub fn from_backtracking_min_path(
+        height: usize,
+        width: usize,
+        min_len: usize,
+        attempts: usize,
+    ) -> Option<Self> {
+        (0..attempts)
+            .filter_map(|_| Self::from_backtracking(height, width))
+            .find(|maze| {
+                let [start, end, ..] = *maze.entry_cells().as_slice() else {
+                    return false;
+                };
+                maze.shortest_path_length(start, end)
+                    .is_some_and(|length| length >= min_len)
+            })
+    }
+
+    /// Rejects a maze with no rows or columns.
+    ///
+    /// Every generator calls this before touching its `Rng`, since there is
+    /// no perimeter cell to choose on an empty board.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if both are positive, otherwise `MazeError::ZeroDimension`.
+    const fn check_dimensions(
+        height: usize,
+        width: usize,
+    ) -> Result<(), MazeError> {
+        if height == 0 || width == 0 {
+            return Err(MazeError::ZeroDimension);
+        }
+
+        Ok(())
+    }
+
+    /// Runs the backtracker with the entrance and exit on some sides.
+    ///
+    /// ### Parameters
+    /// * `height`: The number of maze rows.
+    /// * `width`: The number of maze columns.
+    /// * `entry_sides`: The sides the entrance may be put on.
+    /// * `exit_sides`: The sides the exit may be put on.
+    /// * `rng`: The source of every random choice made during generation.
+    ///
+    /// ### Returns
+    /// * The fully generated maze, or the `MazeError` that stopped generation.
+    fn backtrack_between_sides<R>(
+        height: usize,
+        width: usize,
+        entry_sides: &[Direction],
+        exit_sides: &[Direction],
+        rng: &mut R,
+    ) -> Result<Self, MazeError>
+    where
+        R: Rng + ?Sized,
+    {
+        Self::check_dimensions(height, width)?;
+
+        let mut board = Board::<Tile>::new(height, width);
+        let mut visited = Board::<VisitStatus>::new(height, width);
+        Self::backtrack_into(
+            &mut board,
+            &mut visited,
+            entry_sides,
+            exit_sides,
+            0.0,
+            rng,
+        )?;
+
+        Ok(Self { board })
+    }
+
+    /// Runs the backtracker over a fresh all-wall board.
+    ///
+    /// ### Parameters
+    /// * `board`: The all-wall board to carve the maze into.
+    /// * `visited`: The all-unvisited status of each tile in the maze.
+    /// * `entry_sides`: The sides the entrance may be put on.
+    /// * `exit_sides`: The sides the exit may be put on.
+    /// * `bias`: The chance of carving straight on, where `0.0` is uniform.
+    /// * `rng`: The source of every random choice made during generation.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if carving succeeded, otherwise the `MazeError` hit.
+    fn backtrack_into<R>(
+        board: &mut Board<Tile>,
+        visited: &mut Board<VisitStatus>,
+        entry_sides: &[Direction],
+        exit_sides: &[Direction],
+        bias: f64,
+        rng: &mut R,
+    ) -> Result<(), MazeError>
+    where
+        R: Rng + ?Sized,
+    {
+        let start = Self::choose_perimeter_pair(board, entry_sides, rng)
+            .ok_or(MazeError::PerimeterSelectionFailed)?;
+        Self::add_maze_entry(start, entry_sides, board);
+
+        Self::carve_straight_passages(board, visited, start.pair, bias, rng)?;
+
+        let end = Self::choose_perimeter_pair(board, exit_sides, rng)
+            .ok_or(MazeError::PerimeterSelectionFailed)?;
+        Self::visit_and_mark_as_path(board, visited, end.pair)?;
+        Self::add_maze_entry(end, exit_sides, board);
+
+        Ok(())
+    }
+
+    /// Carves passages by backtracking from a cell until every reachable cell
+    /// is visited.
+    ///
+    /// ### Parameters
+    /// * `board`: The board of the maze.
+    /// * `visited`: The visitation status of each tile in the maze.
+    /// * `start`: The cell to start carving from.
+    /// * `rng`: The source of randomness.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if carving succeeded, otherwise the `MazeError` hit.
+    fn carve_passages<R>(
+        board: &mut Board<Tile>,
+        visited: &mut Board<VisitStatus>,
+        start: Pair,
+        rng: &mut R,
+    ) -> Result<(), MazeError>
+    where
+        R: Rng + ?Sized,
+    {
+        Self::carve_straight_passages(board, visited, start, 0.0, rng)
+    }
+
+    /// Carves passages by backtracking from a cell, preferring to keep going
+    /// in the same direction, until every reachable cell is visited.
+    ///
+    /// ### Parameters
+    /// * `board`: The board of the maze.
+    /// * `visited`: The visitation status of each tile in the maze.
+    /// * `start`: The cell to start carving from.
+    /// * `bias`: The chance, clamped to `0.0..=1.0`, of repeating the move
+    ///   into a cell when it is still unvisited.
+    /// * `rng`: The source of randomness.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if carving succeeded, otherwise the `MazeError` hit.
+    fn carve_straight_passages<R>(
+        board: &mut Board<Tile>,
+        visited: &mut Board<VisitStatus>,
+        start: Pair,
+        bias: f64,
+        rng: &mut R,
+    ) -> Result<(), MazeError>
+    where
+        R: Rng + ?Sized,
+    {
+        let bias = if bias.is_nan() {
+            0.0
+        } else {
+            bias.clamp(0.0, 1.0)
+        };
+        // each frame remembers the move that reached it
+        let mut visited_stack: Stack<(Pair, Option<Direction>)> = Stack::new();
+
+        visited_stack.push((start, None));
+        Self::visit_and_mark_as_path(board, visited, start)?;
+
+        while let Some((popped_pair, last_direction)) = visited_stack.top() {
+            let straight = last_direction.filter(|direction| {
+                bias > 0.0
+                    && Self::get_unvisited_directions(popped_pair, visited)
+                        .contains(direction)
+                    && rng.gen_bool(bias)
+            });
+            let Some(direction) = straight.or_else(|| {
+                Self::choose_random_unvisited_direction(
+                    popped_pair,
+                    visited,
+                    rng,
+                )
+            }) else {
+                visited_stack.pop();
+                Self::visit_and_mark_as_path(board, visited, popped_pair)?;
+                continue;
+            };
+
+            let new_pair = popped_pair.add(direction.cell_step());
+            visited_stack.push((new_pair, Some(direction)));
+            Self::visit_and_mark_as_path(board, visited, new_pair)?;
+
+            // the in-between cell should be a wall, which we can remove
+            let in_between_pair = popped_pair.add(Pair::from(direction));
+            Self::visit_and_mark_as_path(board, visited, in_between_pair)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the board and its visitation status against some pair.
+    ///
+    /// ### Parameters
+    /// * `board`: The board of the maze.
+    /// * `visited`: The visitation status of each tile in the maze.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if the update succeeded, otherwise
+    ///   `MazeError::IndexOutOfBounds` if there was an indexing issue.
+    fn visit_and_mark_as_path(
+        board: &mut Board<Tile>,
+        visited: &mut Board<VisitStatus>,
+        pair: Pair,
+    ) -> Result<(), MazeError> {
+        *board
+            .get_mut_from_pair(pair)
+            .ok_or(MazeError::IndexOutOfBounds)? = Tile::Path;
+        *visited
+            .get_mut_from_pair(pair)
+            .ok_or(MazeError::IndexOutOfBounds)? = VisitStatus::Visited;
+
+        Ok(())
+    }
+
+    /// Adds an entry point to the maze.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` adjacent to the perimeter of the board.
+    /// * `sides`: The sides the entry may be put on.
+    /// * `board`: The board of `Tiles` to update.
+    fn add_maze_entry(
+        perimeter_tile: Perimeter,
+        sides: &[Direction],
+        board: &mut Board<Tile>,
+    ) {
+        let Some(direction) =
+            Self::closed_perimeter_side(perimeter_tile, sides, board)
+        else {
+            return;
+        };
+
+        let Some(cell) = board
+            .get_mut_from_pair(perimeter_tile.pair.add(Pair::from(direction)))
+        else {
+            return;
+        };
+
+        *cell = Tile::Entry;
+    }
+
+    /// Finds a side of a perimeter cell that faces out of the maze and has
+    /// not been opened yet.
+    ///
+    /// ### Parameters
+    /// * `perimeter_tile`: The cell on the perimeter of the board.
+    /// * `sides`: The sides to consider.
+    /// * `board`: The board of `Tiles` to check.
+    ///
+    /// ### Returns
+    /// * The first such side, or `None` if every outward side is open.
+    fn closed_perimeter_side(
+        perimeter_tile: Perimeter,
+        sides: &[Direction],
+        board: &Board<Tile>,
+    ) -> Option<Direction> {
+        sides.iter().copied().find(|direction| {
+            let outside = perimeter_tile.pair.add(direction.cell_step());
+            let wall = perimeter_tile.pair.add(Pair::from(*direction));
+            board.get_from_pair(outside).is_none()
+                && board.get_from_pair(wall) == Some(&Tile::Wall)
+        })
+    }
+
+    /// Chooses a `Pair` from the perimeter of the maze.
+    ///
+    /// Cells whose outward sides are all already open are skipped, so that
+    /// the exit never reuses the entrance, even in a single-cell maze.
+    ///
+    /// ### Parameters
+    /// * `board`: A reference to the board to get a perimeter cell from.
+    /// * `sides`: The sides the cell may open onto.
+    /// * `rng`: The source of randomness.
+    ///
+    /// ### Returns
+    /// * An optional pair, chosen uniformly among the eligible border cells.
+    fn choose_perimeter_pair<R>(
+        board: &Board<Tile>,
+        sides: &[Direction],
+        rng: &mut R,
+    ) -> Option<Perimeter>
+    where
+        R: Rng + ?Sized,
+    {
+        let pair = *board
+            .perimeter_cells()
+            .filter(|pair| {
+                Self::closed_perimeter_side(
+                    Perimeter { pair: *pair },
+                    sides,
+                    board,
+                )
+                .is_some()
+            })
+            .collect::<Vec<Pair>>()
+            .choose(rng)?;
+
+        Some(Perimeter { pair })
+    }
+
+    /// Lists the closed outer walls of the perimeter cells on positively
+    /// weighted sides.
+    ///
+    /// ### Parameters
+    /// * `board`: The board to find openings on.
+    /// * `weights`: The weight of each side, in `Direction` order.
+    ///
+    /// ### Returns
+    /// * Each perimeter cell, the side it would open onto and that side's
+    ///   weight.
+    fn weighted_openings(
+        board: &Board<Tile>,
+        weights: [f64; 4],
+    ) -> Vec<(Pair, Direction, f64)> {
+        board
+            .perimeter_cells()
+            .flat_map(|pair| {
+                Direction::ALL
+                    .into_iter()
+                    .zip(weights)
+                    .filter(move |(direction, weight)| {
+                        *weight > 0.0
+                            && Self::closed_perimeter_side(
+                                Perimeter { pair },
+                                &[*direction],
+                                board,
+                            )
+                            .is_some()
+                    })
+                    .map(move |(direction, weight)| (pair, direction, weight))
+            })
+            .collect()
+    }
+
+    /// Chooses a perimeter cell and the side to open it onto, weighted by
+    /// side.
+    ///
+    /// ### Parameters
+    /// * `board`: The board to choose an opening on.
+    /// * `weights`: The weight of each side, in `Direction` order.
+    /// * `rng`: The source of randomness.
+    ///
+    /// ### Returns
+    /// * The perimeter cell and side, or `None` if there is no opening left.
+    fn choose_weighted_opening<R>(
+        board: &Board<Tile>,
+        weights: [f64; 4],
+        rng: &mut R,
+    ) -> Option<(Perimeter, Direction)>
+    where
+        R: Rng + ?Sized,
+    {
+        let openings = Self::weighted_openings(board, weights);
+        let index =
+            WeightedIndex::new(openings.iter().map(|(_, _, weight)| *weight))
+                .ok()?
+                .sample(rng);
+        let (pair, direction, _) = *openings.get(index)?;
+
+        Some((Perimeter { pair }, direction))
+    }
+
+    /// Places items in randomly chosen dead ends.
+    ///
+    /// ### Parameters
+    /// * `count`: The most items to place.
+    /// * `rng`: The source of the random choice of dead ends.
+    ///
+    /// ### Returns
+    /// * The distinct cells now holding a `Tile::Item`, which is every dead
+    ///   end if there are fewer than `count` of them.
+    #[inline]
+    pub fn place_items<R>(&mut self, count: usize, rng: &mut R) -> Vec<Pair>
+    where
+        R: Rng + ?Sized,
+    {
+        let dead_ends = self
+            .dead_ends_with_direction()
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect::<Vec<Pair>>();
+        let chosen = dead_ends
+            .choose_multiple(rng, count)
+            .copied()
+            .collect::<Vec<Pair>>();
+
+        for cell in &chosen {
+            if let Some(tile) = self.board.get_mut_from_pair(*cell) {
+                *tile = Tile::Item;
+            }
+        }
+
+        chosen
+    }
+
+    /// Choosese a random unvisited direction.
+    ///
+    /// ### Parameters
+    /// * `pair`: A `Pair` to access a `Board` with.
+    /// * `visited`: The `Board` of visitation status.
+    /// * `rng`: The source of randomness.
+    ///
+    /// ### Returns
+    /// * An optional direction.
+    #[inline]
+    #[must_use]
+    pub fn choose_random_unvisited_direction<R>(
+        pair: Pair,
+        visited: &Board<VisitStatus>,
+        rng: &mut R,
+    ) -> Option<Direction>
+    where
+        R: Rng + ?Sized,
+    {
+        let direction_choices = Self::get_unvisited_directions(pair, visited);
+        // keep a fixed order so that the same `Rng` state picks the same move
+        Direction::iter()
+            .filter(|direction| direction_choices.contains(direction))
+            .collect::<Vec<Direction>>()
+            .choose(rng)
+            .copied()
+    }
+
+    /// Get all valid directions for the current cell.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` to check valid directions for.
+    /// * `board`: The `Board` to check the `Pair` against.
+    ///
+    /// ### Returns
+    /// * A `HashSet` of `Directions`.
+    fn get_possible_directions<T>(
+        pair: Pair,
+        board: &Board<T>,
+    ) -> HashSet<Direction>
+    where
+        T: Sized,
+    {
+        Direction::iter()
+            .filter(|direction| {
+                board
+                    .get_from_pair(pair.add(direction.cell_step()))
+                    .is_some()
+            })
+            .collect::<HashSet<Direction>>()
+    }
+
+    /// Gets unvisited directions.
+    ///
+    /// ### Parameters
+    /// * `pair`: A `Pair` to access a `Board` with.
+    /// * `visited`: The `Board` of visitation status.
+    ///
+    /// ### Returns
+    /// * A set of `Direction` enums.
+    #[inline]
+    #[must_use]
+    pub fn get_unvisited_directions(
+        pair: Pair,
+        visited: &Board<VisitStatus>,
+    ) -> HashSet<Direction> {
+        Self::get_possible_directions::<VisitStatus>(pair, visited)
+            .into_iter()
+            .filter(|direction| {
+                let Some(visit_status_of_new_pair) =
+                    visited.get_from_pair(pair.add(direction.cell_step()))
+                else {
+                    return false;
+                };
                 *visit_status_of_new_pair == VisitStatus::Unvisited
             })
             .collect::<HashSet<Direction>>()
@@ -348,15 +1587,14 @@ impl Maze {
 mod test_maze {
     use std::collections::HashSet;
 
-    use core::ops::Mul;
+    use rand::{rngs::StdRng, SeedableRng};
     use strum::IntoEnumIterator;
 
     use crate::{
-        board::{Board, CELL_STEP},
-        direction::Direction,
-        maze::Maze,
-        pair::Pair,
-        visit_status::VisitStatus,
+        axis::Axis, board::Board, direction::Direction, edge::Edge,
+        entrance_spec::EntranceSpec, glyphs::Glyphs, maze::Maze,
+        maze_error::MazeError, pair::Pair, test_support::ScriptedRng,
+        tile::Tile, visit_status::VisitStatus,
     };
 
     #[test]
@@ -366,18 +1604,18 @@ mod test_maze {
         let none_visited = Direction::iter().collect::<HashSet<Direction>>();
         assert_eq!(Maze::get_unvisited_directions(pair, &board), none_visited);
 
-        if let Some(cell) = board.get_mut_from_pair(
-            pair + CELL_STEP.mul(Pair::from(Direction::Left)),
-        ) {
+        if let Some(cell) =
+            board.get_mut_from_pair(pair + Direction::Left.cell_step())
+        {
             *cell = VisitStatus::Visited;
         }
         let mut left_visited = none_visited;
         let _: bool = left_visited.remove(&Direction::Left);
         assert_eq!(Maze::get_unvisited_directions(pair, &board), left_visited);
 
-        if let Some(cell) = board.get_mut_from_pair(
-            pair + CELL_STEP.mul(Pair::from(Direction::Right)),
-        ) {
+        if let Some(cell) =
+            board.get_mut_from_pair(pair + Direction::Right.cell_step())
+        {
             *cell = VisitStatus::Visited;
         }
         let mut left_and_right_visited = left_visited;
@@ -387,4 +1625,730 @@ mod test_maze {
             left_and_right_visited
         );
     }
+
+    #[test]
+    fn test_new() {
+        let maze = Maze::new(2, 3);
+        let rendered = maze.to_string();
+
+        assert_eq!(rendered.lines().count(), 5);
+        for line in rendered.lines() {
+            assert_eq!(line.chars().count(), 7);
+            assert!(line.chars().all(|glyph| glyph == Glyphs::blocks().wall));
+        }
+    }
+
+    #[test]
+    fn test_get_tile() {
+        let mut maze = Maze::new(2, 3);
+        if let Some(tile) =
+            maze.board.get_mut_from_pair(Pair::from_row_and_col(3, 5))
+        {
+            *tile = Tile::Path;
+        }
+
+        assert_eq!(maze.get_tile(1, 2), Some(&Tile::Path));
+        assert_eq!(maze.get_tile(0, 0), Some(&Tile::Wall));
+        assert_eq!(maze.get_tile(2, 0), None);
+        assert_eq!(maze.get_tile(0, 3), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        let Some(mut maze) = Maze::from_backtracking(4, 5) else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(maze.validate(), Ok(()));
+
+        let cell = Pair::from_row_and_col(3, 3);
+        let walled_off = Direction::iter()
+            .map(|direction| cell + Pair::from(direction))
+            .chain([cell]);
+        for pair in walled_off {
+            if let Some(tile) = maze.board.get_mut_from_pair(pair) {
+                *tile = Tile::Wall;
+            }
+        }
+        assert!(matches!(
+            maze.validate(),
+            Err(MazeError::UnreachableCell(_))
+        ));
+
+        let corner = Pair::from_row_and_col(2, 2);
+        if let Some(tile) = maze.board.get_mut_from_pair(corner) {
+            *tile = Tile::Path;
+        }
+        assert_eq!(maze.validate(), Err(MazeError::MisplacedPassage(corner)));
+    }
+
+    #[test]
+    fn test_validate_broken_structure() {
+        assert_eq!(Maze::new(3, 3).validate(), Err(MazeError::MissingEntry));
+
+        let mut maze = Maze::new(3, 3);
+        maze.board.grid.pop();
+        assert_eq!(maze.validate(), Err(MazeError::InvalidDimensions));
+    }
+
+    #[test]
+    fn test_try_from_backtracking_zero_dimension() {
+        for (height, width) in [(0, 0), (0, 5), (5, 0)] {
+            assert_eq!(
+                Maze::try_from_backtracking(height, width).err(),
+                Some(MazeError::ZeroDimension)
+            );
+            assert!(Maze::from_backtracking(height, width).is_none());
+
+            // an empty script panics if the rng is touched at all
+            let rng = &mut ScriptedRng::new([]);
+            assert_eq!(
+                Maze::try_from_backtracking_with_rng(height, width, rng).err(),
+                Some(MazeError::ZeroDimension)
+            );
+            let mut board = Board::<Tile>::new(height, width);
+            let mut visited = Board::<VisitStatus>::new(height, width);
+            assert!(
+                Maze::regenerate_into(&mut board, &mut visited, rng).is_none()
+            );
+
+            assert!(Maze::from_kruskals(height, width).is_none());
+            assert!(Maze::from_backtracking_sides(
+                height,
+                width,
+                Direction::Up,
+                Direction::Down
+            )
+            .is_none());
+            assert!(Maze::from_backtracking_side_weights(
+                height, width, [1.0; 4]
+            )
+            .is_none());
+            assert!(Maze::from_backtracking_straight_bias(height, width, 0.5)
+                .is_none());
+            assert!(Maze::from_backtracking_symmetric(
+                height,
+                width,
+                Axis::Vertical
+            )
+            .is_none());
+            assert!(Maze::from_backtracking_entrances(
+                height,
+                width,
+                EntranceSpec::Corners
+            )
+            .is_none());
+            assert!(Maze::from_backtracking_toroidal(height, width).is_none());
+            assert!(
+                Maze::from_backtracking_parallel(height, width, 1).is_none()
+            );
+        }
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let maze = Maze::new(2, 2);
+
+        let mut count = 0;
+        for (pair, tile) in &maze {
+            assert_eq!(maze.board.get_from_pair(pair), Some(tile));
+            count += 1;
+        }
+        assert_eq!(count, 25);
+    }
+
+    #[test]
+    fn test_from_backtracking_with_scripted_rng() {
+        let mut rng = ScriptedRng::new([
+            // the entrance is on the first perimeter cell
+            ScriptedRng::choice(0, 2),
+            // the only unvisited direction is to the right
+            ScriptedRng::choice(0, 1),
+            // the exit is on the second perimeter cell
+            ScriptedRng::choice(1, 2),
+        ]);
+
+        let Some(maze) = Maze::from_backtracking_with_rng(1, 2, &mut rng)
+        else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(maze.render(&Glyphs::ascii()), "#E#E#\n#   #\n#####\n");
+    }
+
+    #[test]
+    fn test_from_backtracking_single_cell() {
+        for _ in 0..100 {
+            let Some(maze) = Maze::from_backtracking(1, 1) else {
+                panic!("failed to generate maze");
+            };
+
+            let entries = maze
+                .board
+                .iter_cells()
+                .filter(|(_, tile)| **tile == Tile::Entry)
+                .count();
+            assert_eq!(entries, 2);
+            assert_eq!(maze.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_from_backtracking_sides() {
+        for _ in 0..20 {
+            let Some(maze) = Maze::from_backtracking_sides(
+                4,
+                6,
+                Direction::Left,
+                Direction::Right,
+            ) else {
+                panic!("failed to generate maze");
+            };
+
+            let entries = maze
+                .board
+                .iter_cells()
+                .filter(|(_, tile)| **tile == Tile::Entry)
+                .map(|(pair, _)| pair.col)
+                .collect::<Vec<i32>>();
+            assert_eq!(entries.len(), 2);
+            assert!(entries.contains(&0));
+            assert!(entries.contains(&12));
+        }
+
+        assert!(Maze::from_backtracking_sides(
+            1,
+            3,
+            Direction::Up,
+            Direction::Up
+        )
+        .is_some());
+        assert!(Maze::from_backtracking_sides(
+            3,
+            1,
+            Direction::Up,
+            Direction::Up
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_from_backtracking_symmetric() {
+        for (height, width) in [(4, 6), (5, 7), (1, 1), (2, 3), (1, 4), (3, 1)]
+        {
+            let Some(maze) = Maze::from_backtracking_symmetric(
+                height,
+                width,
+                Axis::Vertical,
+            ) else {
+                panic!("failed to generate maze");
+            };
+            let rendered = maze.render(&Glyphs::ascii());
+            for line in rendered.lines() {
+                assert_eq!(line, line.chars().rev().collect::<String>());
+            }
+            assert_solvable(&maze);
+            assert_eq!(maze.passages().len(), height * width - 1);
+
+            let Some(maze) = Maze::from_backtracking_symmetric(
+                height,
+                width,
+                Axis::Horizontal,
+            ) else {
+                panic!("failed to generate maze");
+            };
+            let rendered = maze.render(&Glyphs::ascii());
+            let lines = rendered.lines().collect::<Vec<&str>>();
+            assert!(lines.iter().eq(lines.iter().rev()));
+            assert_solvable(&maze);
+            assert_eq!(maze.passages().len(), height * width - 1);
+        }
+    }
+
+    /// Asserts that the entrances of a maze are connected.
+    fn assert_solvable(maze: &Maze) {
+        let entries = maze.entry_cells();
+        assert_eq!(entries.len(), 2);
+        let (Some(start), Some(end)) = (entries.first(), entries.last()) else {
+            panic!("expected two entrances");
+        };
+        assert!(maze.solve_dfs(*start, *end).is_some());
+        assert_eq!(maze.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_from_name() {
+        let (Some(first), Some(second), Some(other)) = (
+            Maze::from_name(10, 10, "hello"),
+            Maze::from_name(10, 10, "hello"),
+            Maze::from_name(10, 10, "world"),
+        ) else {
+            panic!("failed to generate maze");
+        };
+
+        assert_eq!(first.to_string(), second.to_string());
+        assert_ne!(first.to_string(), other.to_string());
+
+        // published FNV-1a test vectors, so the seed cannot drift
+        assert_eq!(Maze::name_seed(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(Maze::name_seed("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(Maze::name_seed("foobar"), 0x8594_4171_f739_67e8);
+        assert_eq!(
+            Some(first),
+            Maze::from_backtracking_with_rng(
+                10,
+                10,
+                &mut StdRng::seed_from_u64(Maze::name_seed("hello"))
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_edges() {
+        let cell = |row, col| {
+            let Some(pair) = Board::<Tile>::cell_position_to_pair(row, col)
+            else {
+                panic!("cell out of range");
+            };
+            pair
+        };
+        let edges = HashSet::from([
+            Edge {
+                pairs: (cell(0, 0), cell(0, 1)),
+            },
+            Edge {
+                pairs: (cell(1, 1), cell(0, 1)),
+            },
+            Edge {
+                pairs: (cell(1, 0), cell(1, 1)),
+            },
+        ]);
+        let Some(maze) = Maze::from_edges(2, 2, &edges) else {
+            panic!("failed to build maze");
+        };
+
+        assert_eq!(
+            maze.to_string(),
+            "⬛⬛⬛⬛⬛\n⬛⬜⬜⬜⬛\n⬛⬛⬛⬜⬛\n⬛⬜⬜⬜⬛\n⬛⬛⬛⬛⬛\n"
+        );
+        assert!(maze.solve_dfs(cell(0, 0), cell(1, 0)).is_some());
+
+        let diagonal = HashSet::from([Edge {
+            pairs: (cell(0, 0), cell(1, 1)),
+        }]);
+        assert!(Maze::from_edges(2, 2, &diagonal).is_none());
+
+        let outside = HashSet::from([Edge {
+            pairs: (cell(1, 1), cell(1, 2)),
+        }]);
+        assert!(Maze::from_edges(2, 2, &outside).is_none());
+    }
+
+    #[test]
+    fn test_passages() {
+        let edges = [((1, 1), (1, 3)), ((3, 3), (1, 3)), ((3, 1), (3, 3))]
+            .into_iter()
+            .map(|((first_row, first_col), (second_row, second_col))| {
+                Edge::new(
+                    Pair::from_row_and_col(first_row, first_col),
+                    Pair::from_row_and_col(second_row, second_col),
+                )
+            })
+            .collect::<HashSet<Edge>>();
+        let Some(maze) = Maze::from_edges(2, 2, &edges) else {
+            panic!("failed to build maze");
+        };
+        assert_eq!(maze.passages(), edges);
+
+        let Some(maze) = Maze::from_backtracking(6, 9) else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(maze.passages().len(), 6 * 9 - 1);
+    }
+
+    #[test]
+    fn test_place_items() {
+        let generate = || {
+            let Some(maze) = Maze::from_backtracking_with_rng(
+                6,
+                6,
+                &mut StdRng::seed_from_u64(3),
+            ) else {
+                panic!("failed to generate maze");
+            };
+            maze
+        };
+        let mut first = generate();
+        let mut second = generate();
+        let dead_ends = first
+            .dead_ends_with_direction()
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect::<Vec<Pair>>();
+
+        let items = first.place_items(3, &mut StdRng::seed_from_u64(7));
+        assert_eq!(items, second.place_items(3, &mut StdRng::seed_from_u64(7)));
+        assert_eq!(items.len(), 3.min(dead_ends.len()));
+        for item in &items {
+            assert!(dead_ends.contains(item));
+            assert_eq!(first.board.get_from_pair(*item), Some(&Tile::Item));
+        }
+        assert_eq!(first.validate(), Ok(()));
+
+        let mut all = generate();
+        let items = all.place_items(usize::MAX, &mut StdRng::seed_from_u64(7));
+        assert_eq!(items.len(), dead_ends.len());
+    }
+
+    #[test]
+    fn test_regenerate_into() {
+        let mut board = Board::<Tile>::new(6, 8);
+        let mut visited = Board::<VisitStatus>::new(6, 8);
+        let rng = &mut StdRng::seed_from_u64(5);
+
+        assert_eq!(
+            Maze::regenerate_into(&mut board, &mut visited, rng),
+            Some(())
+        );
+        let first = Maze {
+            board: board.clone(),
+        };
+        assert_eq!(first.validate(), Ok(()));
+
+        assert_eq!(
+            Maze::regenerate_into(&mut board, &mut visited, rng),
+            Some(())
+        );
+        let second = Maze { board };
+        assert_eq!(second.validate(), Ok(()));
+        assert_ne!(first.board, second.board);
+
+        let mut board = Board::<Tile>::new(6, 8);
+        let mut visited = Board::<VisitStatus>::new(8, 6);
+        assert_eq!(Maze::regenerate_into(&mut board, &mut visited, rng), None);
+    }
+
+    #[test]
+    fn test_from_backtracking_parallel() {
+        for (height, width) in [(9, 14), (1, 5), (6, 1), (1, 1)] {
+            let (Some(first), Some(second)) = (
+                Maze::from_backtracking_parallel(height, width, 42),
+                Maze::from_backtracking_parallel(height, width, 42),
+            ) else {
+                panic!("failed to generate maze");
+            };
+
+            assert_eq!(first.board, second.board);
+            assert_eq!(first.validate(), Ok(()));
+            assert!(first.is_solvable());
+            assert_eq!(first.passages().len(), height * width - 1);
+        }
+    }
+
+    #[test]
+    fn test_from_backtracking_side_weights() {
+        for _ in 0..10 {
+            let Some(maze) = Maze::from_backtracking_side_weights(
+                5,
+                5,
+                [0.0, 0.0, 0.0, 1.0],
+            ) else {
+                panic!("failed to generate maze");
+            };
+            let entries = maze
+                .board
+                .iter_cells()
+                .filter(|(_, tile)| **tile == Tile::Entry)
+                .map(|(pair, _)| pair)
+                .collect::<Vec<Pair>>();
+
+            assert_eq!(entries.len(), 2);
+            assert!(entries.iter().all(|entry| entry.col == 0));
+        }
+
+        assert!(Maze::from_backtracking_side_weights(5, 5, [0.0; 4]).is_none());
+        assert!(Maze::from_backtracking_side_weights(
+            5,
+            5,
+            [1.0, -1.0, 0.0, 0.0]
+        )
+        .is_none());
+
+        // every weighted side here has room for only one opening, so the
+        // entrance and exit must each take a different side
+        for _ in 0..20 {
+            let (Some(single), Some(column)) = (
+                Maze::from_backtracking_side_weights(
+                    1,
+                    1,
+                    [1.0, 0.0, 0.0, 1.0],
+                ),
+                Maze::from_backtracking_side_weights(
+                    4,
+                    1,
+                    [1.0, 0.0, 1.0, 0.0],
+                ),
+            ) else {
+                panic!("failed to generate maze");
+            };
+            assert_eq!(single.validate(), Ok(()));
+            assert_eq!(
+                single.board.get_from_pair(Pair::from_row_and_col(0, 1)),
+                Some(&Tile::Entry)
+            );
+            assert_eq!(
+                single.board.get_from_pair(Pair::from_row_and_col(1, 0)),
+                Some(&Tile::Entry)
+            );
+            assert_eq!(column.validate(), Ok(()));
+            assert!(column.is_solvable());
+            assert_eq!(
+                column.board.get_from_pair(Pair::from_row_and_col(0, 1)),
+                Some(&Tile::Entry)
+            );
+            assert_eq!(
+                column.board.get_from_pair(Pair::from_row_and_col(8, 1)),
+                Some(&Tile::Entry)
+            );
+        }
+
+        // a single opening can never hold both the entrance and the exit
+        assert!(Maze::from_backtracking_side_weights(
+            3,
+            1,
+            [1.0, 0.0, 0.0, 0.0]
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_from_backtracking_straight_bias() {
+        let total_turns = |bias| {
+            (0..20)
+                .map(|_| {
+                    let Some(maze) =
+                        Maze::from_backtracking_straight_bias(15, 15, bias)
+                    else {
+                        panic!("failed to generate maze");
+                    };
+                    assert_eq!(maze.validate(), Ok(()));
+                    count_turns(&maze)
+                })
+                .sum::<usize>()
+        };
+
+        assert!(total_turns(0.9) < total_turns(0.0));
+    }
+
+    /// Counts the cells where a corridor bends instead of going straight.
+    fn count_turns(maze: &Maze) -> usize {
+        maze.board
+            .iter_cells()
+            .filter(|(pair, _)| pair.is_cell())
+            .filter(|(pair, _)| {
+                let open = Direction::iter()
+                    .filter(|direction| {
+                        maze.board
+                            .get_from_pair(*pair + Pair::from(*direction))
+                            .is_some_and(Tile::is_passable)
+                    })
+                    .collect::<Vec<Direction>>();
+                matches!(
+                    *open.as_slice(),
+                    [first, second] if second != first.turn_right().turn_right()
+                )
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_diff() {
+        let Some(maze) = Maze::from_backtracking(4, 4) else {
+            panic!("failed to generate maze");
+        };
+        let mut mutated = Maze {
+            board: maze.board.clone(),
+        };
+        let pillar = Pair::from_row_and_col(2, 2);
+        if let Some(tile) = mutated.board.get_mut_from_pair(pillar) {
+            *tile = Tile::Path;
+        }
+
+        assert_eq!(maze.diff(&maze), Some(vec![]));
+        assert_eq!(
+            maze.diff(&mutated),
+            Some(vec![(pillar, Tile::Wall, Tile::Path)])
+        );
+        assert_eq!(maze.diff(&Maze::new(4, 5)), None);
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        let generate = || {
+            Maze::from_backtracking_with_rng(
+                5,
+                5,
+                &mut StdRng::seed_from_u64(9),
+            )
+        };
+        let (Some(first), Some(second)) = (generate(), generate()) else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(first, second);
+
+        let mazes = HashSet::from([first, second]);
+        assert_eq!(mazes.len(), 1);
+        assert!(!mazes.contains(&Maze::new(5, 5)));
+    }
+
+    #[test]
+    fn test_from_backtracking_toroidal() {
+        let (height, width) = (6, 8);
+        let Some(maze) = Maze::from_backtracking_toroidal(height, width) else {
+            panic!("failed to generate maze");
+        };
+
+        let seams = maze
+            .board
+            .iter_cells()
+            .filter(|(pair, tile)| {
+                (pair.row == 0 || pair.col == 0) && tile.is_passable()
+            })
+            .count();
+        assert!(seams > 0);
+        assert_eq!(maze.passages().len() + seams, height * width - 1);
+
+        let start = Pair::from_row_and_col(1, 1);
+        let mut reached = HashSet::from([start]);
+        let mut frontier = vec![start];
+        while let Some(cell) = frontier.pop() {
+            for direction in Direction::iter() {
+                if !maze
+                    .board
+                    .get_from_pair(cell + Pair::from(direction))
+                    .is_some_and(Tile::is_passable)
+                {
+                    continue;
+                }
+                let Some(neighbor) =
+                    Maze::wrap_cell(&maze.board, cell + direction.cell_step())
+                else {
+                    panic!("failed to wrap cell");
+                };
+                if reached.insert(neighbor) {
+                    frontier.push(neighbor);
+                }
+            }
+        }
+        assert_eq!(reached.len(), height * width);
+        assert!(Maze::from_backtracking_toroidal(0, 3).is_none());
+    }
+
+    #[test]
+    fn test_from_backtracking_entrances() {
+        let entries = |maze: &Maze| {
+            maze.board
+                .iter_cells()
+                .filter(|(_, tile)| **tile == Tile::Entry)
+                .map(|(pair, _)| pair)
+                .collect::<Vec<Pair>>()
+        };
+
+        let Some(maze) =
+            Maze::from_backtracking_entrances(4, 5, EntranceSpec::Count(3))
+        else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(entries(&maze).len(), 3);
+        assert_eq!(maze.validate(), Ok(()));
+
+        let Some(maze) =
+            Maze::from_backtracking_entrances(4, 5, EntranceSpec::Corners)
+        else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(
+            entries(&maze),
+            [(0, 1), (1, 10), (7, 0), (8, 9)]
+                .map(|(row, col)| Pair::from_row_and_col(row, col))
+        );
+        assert_eq!(maze.validate(), Ok(()));
+
+        let explicit =
+            vec![Pair::from_row_and_col(0, 3), Pair::from_row_and_col(5, 10)];
+        let Some(maze) = Maze::from_backtracking_entrances(
+            4,
+            5,
+            EntranceSpec::Explicit(explicit.clone()),
+        ) else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(entries(&maze), explicit);
+        assert_eq!(maze.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_from_backtracking_entrances_invalid() {
+        let explicit = |pairs: &[(i32, i32)]| {
+            EntranceSpec::Explicit(
+                pairs
+                    .iter()
+                    .map(|(row, col)| Pair::from_row_and_col(*row, *col))
+                    .collect(),
+            )
+        };
+
+        for spec in [
+            EntranceSpec::Count(0),
+            EntranceSpec::Count(19),
+            explicit(&[]),
+            explicit(&[(0, 0)]),
+            explicit(&[(2, 3)]),
+            explicit(&[(0, 3), (0, 3)]),
+            explicit(&[(0, 11)]),
+        ] {
+            assert!(Maze::from_backtracking_entrances(4, 5, spec).is_none());
+        }
+        assert!(Maze::from_backtracking_entrances(
+            4,
+            5,
+            EntranceSpec::Count(18)
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_from_backtracking_min_path() {
+        // a 2x2 maze has four cells, so no path is longer than three steps
+        assert!(Maze::from_backtracking_min_path(2, 2, 4, 20).is_none());
+        assert!(Maze::from_backtracking_min_path(8, 8, 0, 0).is_none());
+
+        let Some(maze) = Maze::from_backtracking_min_path(8, 8, 6, 200) else {
+            panic!("failed to generate maze");
+        };
+        let [start, end, ..] = *maze.entry_cells().as_slice() else {
+            panic!("expected two entrances");
+        };
+        assert!(maze.shortest_path_length(start, end) >= Some(6));
+        assert_eq!(maze.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let Some(maze) = Maze::from_backtracking(4, 6) else {
+            panic!("failed to generate maze");
+        };
+        assert_eq!(maze.to_string().parse::<Maze>(), Ok(maze));
+
+        assert_eq!(
+            "#E#\n#?#\n###\n".parse::<Maze>(),
+            Err(MazeError::UnknownGlyph('?'))
+        );
+        assert_eq!(
+            "#E##\n#  #\n####\n".parse::<Maze>(),
+            Err(MazeError::InvalidDimensions)
+        );
+        assert_eq!(
+            "#####\n#   #\n#####\n".parse::<Maze>(),
+            Err(MazeError::MissingEntry)
+        );
+    }
 }
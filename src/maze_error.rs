@@ -0,0 +1,63 @@
+//! The ways in which building or checking a maze can fail.
+
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result};
+
+use crate::pair::Pair;
+
+/// Describes why a maze could not be generated or is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MazeError {
+    /// The maze was requested with zero rows or columns.
+    ZeroDimension,
+    /// No cell on the perimeter could be chosen for an entrance.
+    PerimeterSelectionFailed,
+    /// A `Pair` fell outside the board.
+    IndexOutOfBounds,
+    /// The grid does not have `2n + 1` tiles per row and column.
+    InvalidDimensions,
+    /// The maze has no entrance.
+    MissingEntry,
+    /// A cell cannot be reached from any entrance.
+    UnreachableCell(Pair),
+    /// A passable tile lies off the cell/wall lattice.
+    MisplacedPassage(Pair),
+    /// A character in a parsed maze does not draw any tile.
+    UnknownGlyph(char),
+}
+
+impl Display for MazeError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::ZeroDimension => {
+                write!(formatter, "the maze must have at least one cell")
+            }
+            Self::PerimeterSelectionFailed => {
+                write!(formatter, "no perimeter cell could be chosen")
+            }
+            Self::IndexOutOfBounds => {
+                write!(formatter, "a position fell outside the board")
+            }
+            Self::InvalidDimensions => {
+                write!(formatter, "the grid must have 2n + 1 rows and columns")
+            }
+            Self::MissingEntry => write!(formatter, "the maze has no entrance"),
+            Self::UnreachableCell(pair) => write!(
+                formatter,
+                "the cell at ({}, {}) is unreachable",
+                pair.row, pair.col
+            ),
+            Self::MisplacedPassage(pair) => write!(
+                formatter,
+                "the passage at ({}, {}) is off the lattice",
+                pair.row, pair.col
+            ),
+            Self::UnknownGlyph(glyph) => {
+                write!(formatter, "{glyph:?} is not a tile")
+            }
+        }
+    }
+}
+
+impl Error for MazeError {}
@@ -1,11 +1,12 @@
 //! A pair of co-ordinates to enable easy vector access.
 
-use core::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
+use crate::board::CELL_STEP;
 use crate::direction::Direction;
 
 /// Denotes a row-and-column pair to access a 2-D vector.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Pair {
     /// The row to access from.
     pub row: i32,
@@ -21,6 +22,19 @@ pub struct Perimeter {
 }
 
 impl Pair {
+    /// The origin `Pair`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{direction::Direction, pair::Pair};
+    ///
+    /// assert_eq!(
+    ///     Pair::ZERO + Pair::from(Direction::Right),
+    ///     Pair::from_row_and_col(0, 1)
+    /// );
+    /// ```
+    pub const ZERO: Self = Self { row: 0, col: 0 };
+
     /// Creates a `Pair` from a row and col.
     ///
     /// ### Parameters
@@ -41,6 +55,175 @@ impl Pair {
     pub const fn from_row_and_col(row: i32, col: i32) -> Self {
         Self { row, col }
     }
+
+    /// Checks whether the `Pair` fits within some tile dimensions.
+    ///
+    /// ### Parameters
+    /// * `tile_height`: The number of rows available.
+    /// * `tile_width`: The number of columns available.
+    ///
+    /// ### Returns
+    /// * `true` if both co-ordinates are non-negative and less than their
+    ///   respective dimension, otherwise `false`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::pair::Pair;
+    ///
+    /// assert!(Pair::from_row_and_col(2, 4).in_bounds(3, 5));
+    /// assert!(!Pair::from_row_and_col(-1, 4).in_bounds(3, 5));
+    /// assert!(!Pair::from_row_and_col(2, 5).in_bounds(3, 5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn in_bounds(self, tile_height: usize, tile_width: usize) -> bool {
+        usize::try_from(self.row).is_ok_and(|row| row < tile_height)
+            && usize::try_from(self.col).is_ok_and(|col| col < tile_width)
+    }
+
+    /// Moves the `Pair` to the nearest position within some tile dimensions.
+    ///
+    /// ### Parameters
+    /// * `tile_height`: The number of rows available.
+    /// * `tile_width`: The number of columns available.
+    ///
+    /// ### Returns
+    /// * The `Pair` with `row` clamped to `0..tile_height` and `col` clamped
+    ///   to `0..tile_width`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::pair::Pair;
+    ///
+    /// assert_eq!(
+    ///     Pair::from_row_and_col(-3, 4).clamp(5, 7),
+    ///     Pair::from_row_and_col(0, 4)
+    /// );
+    /// assert_eq!(
+    ///     Pair::from_row_and_col(9, 12).clamp(5, 7),
+    ///     Pair::from_row_and_col(4, 6)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, tile_height: usize, tile_width: usize) -> Self {
+        let last = |length: usize| {
+            i32::try_from(length.saturating_sub(1)).unwrap_or(i32::MAX)
+        };
+
+        Self::from_row_and_col(
+            self.row.clamp(0, last(tile_height)),
+            self.col.clamp(0, last(tile_width)),
+        )
+    }
+
+    /// Computes the dot product of two `Pairs`.
+    ///
+    /// ### Parameters
+    /// * `other`: The `Pair` to multiply with this object.
+    ///
+    /// ### Returns
+    /// * The sum of the component-wise products.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{direction::Direction, pair::Pair};
+    ///
+    /// let up = Pair::from(Direction::Up);
+    /// assert_eq!(up.dot(Pair::from(Direction::Right)), 0);
+    /// assert_eq!(up.dot(up), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn dot(self, other: Self) -> i32 {
+        self.row.mul(other.row).add(self.col.mul(other.col))
+    }
+
+    /// Takes the absolute value of each co-ordinate, saturating at
+    /// `i32::MAX` so that `i32::MIN` does not overflow.
+    ///
+    /// ### Returns
+    /// * The `Pair` of `(row.saturating_abs(), col.saturating_abs())`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::pair::Pair;
+    ///
+    /// assert_eq!(
+    ///     Pair::from_row_and_col(-3, 4).abs(),
+    ///     Pair::from_row_and_col(3, 4)
+    /// );
+    /// assert_eq!(
+    ///     Pair::from_row_and_col(i32::MIN, -1).abs(),
+    ///     Pair::from_row_and_col(i32::MAX, 1)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        Self::from_row_and_col(
+            self.row.saturating_abs(),
+            self.col.saturating_abs(),
+        )
+    }
+
+    /// Rotates the `Pair` a quarter turn clockwise about the origin.
+    ///
+    /// ### Returns
+    /// * The `Pair` mapped from `(row, col)` to `(col, -row)`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{direction::Direction, pair::Pair};
+    ///
+    /// let up = Pair::from(Direction::Up);
+    /// assert_eq!(up.rotate_cw(), Pair::from(Direction::Right));
+    ///
+    /// let pair = Pair::from_row_and_col(3, -2);
+    /// assert_eq!(pair.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), pair);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rotate_cw(self) -> Self {
+        Self::from_row_and_col(self.col, self.row.neg())
+    }
+
+    /// Rotates the `Pair` a quarter turn counter-clockwise about the origin.
+    ///
+    /// ### Returns
+    /// * The `Pair` mapped from `(row, col)` to `(-col, row)`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{direction::Direction, pair::Pair};
+    ///
+    /// let up = Pair::from(Direction::Up);
+    /// assert_eq!(up.rotate_ccw(), Pair::from(Direction::Left));
+    ///
+    /// let pair = Pair::from_row_and_col(3, -2);
+    /// assert_eq!(pair.rotate_cw().rotate_ccw(), pair);
+    /// assert_eq!(
+    ///     pair.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw(),
+    ///     pair
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rotate_ccw(self) -> Self {
+        Self::from_row_and_col(self.col.neg(), self.row)
+    }
+
+    /// Checks whether the `Pair` lies on the odd-indexed cell lattice.
+    ///
+    /// ### Returns
+    /// * `true` if both co-ordinates are odd, whether or not the `Pair` is
+    ///   on any particular board.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn is_cell(self) -> bool {
+        self.row.rem_euclid(CELL_STEP) == 1
+            && self.col.rem_euclid(CELL_STEP) == 1
+    }
 }
 
 impl From<Direction> for Pair {
@@ -160,6 +343,39 @@ impl Mul<i32> for Pair {
     }
 }
 
+impl Div<i32> for Pair {
+    type Output = Self;
+
+    /// Divides a `Pair` by a number with component-wise integer division.
+    ///
+    /// Each component is truncated towards zero, as with `i32` division.
+    ///
+    /// ### Parameters
+    /// * `rhs`: The non-zero scalar to divide the `Pair` by.
+    ///
+    /// ### Returns
+    /// * A pair constructed by scalar division.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::pair::Pair;
+    ///
+    /// let lhs = Pair::from_row_and_col(1, 3);
+    /// let rhs = Pair::from_row_and_col(1, 5);
+    /// assert_eq!((lhs + rhs) / 2, Pair::from_row_and_col(1, 4));
+    ///
+    /// let truncated = Pair::from_row_and_col(-3, 3) / 2;
+    /// assert_eq!(truncated, Pair::from_row_and_col(-1, 1));
+    /// ```
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        Self {
+            row: self.row.div(rhs),
+            col: self.col.div(rhs),
+        }
+    }
+}
+
 impl Mul<Pair> for i32 {
     type Output = Pair;
 
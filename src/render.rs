@@ -0,0 +1,778 @@
+//! Alternative text renderings of a maze.
+
+use std::collections::HashSet;
+use std::env;
+use std::ffi::OsStr;
+
+use core::ops::Add;
+
+use crate::direction::Direction;
+use crate::glyphs::Glyphs;
+use crate::maze::Maze;
+use crate::pair::Pair;
+use crate::tile::Tile;
+
+/// The escape sequence that resets all terminal attributes.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// The escape sequence setting the red background of a solution tile.
+const ANSI_SOLUTION: &str = "\x1b[41m";
+
+/// The shades drawn for passable tiles, from cheapest to most expensive.
+const WEIGHT_SHADES: [char; 3] = ['░', '▒', '▓'];
+
+/// A named bundle of glyph and color choices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// The default colored squares.
+    #[default]
+    Classic,
+    /// Solid blocks in strongly contrasting ANSI foreground colors.
+    HighContrast,
+    /// Plain ASCII characters.
+    Ascii,
+}
+
+impl Maze {
+    /// Renders the maze with a set of glyphs.
+    ///
+    /// ### Parameters
+    /// * `glyphs`: The characters to draw each tile with.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn render(&self, glyphs: &Glyphs) -> String {
+        join_rows(&self.render_grid(glyphs))
+    }
+
+    /// Renders the maze one row at a time with the default glyphs.
+    ///
+    /// ### Returns
+    /// * An iterator yielding each rendered row of tiles, without a newline.
+    #[inline]
+    pub fn row_strings(&self) -> impl Iterator<Item = String> + '_ {
+        let glyphs = Glyphs::blocks();
+        self.board
+            .rows()
+            .map(move |row| row.iter().map(|tile| glyphs.glyph(tile)).collect())
+    }
+
+    /// Exports the tiles of the maze as comma-separated integer codes.
+    ///
+    /// Walls are `0`, paths `1` and entrances `2`. Items, which arrived after
+    /// that scheme, extend it as `3`.
+    ///
+    /// ### Returns
+    /// * The CSV text, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut result = String::new();
+
+        for row in self.board.rows() {
+            let codes = row
+                .iter()
+                .map(|tile| csv_code(tile).to_string())
+                .collect::<Vec<String>>();
+            result.push_str(&codes.join(","));
+            result.push('\n');
+        }
+
+        result
+    }
+
+    /// Renders the maze shaded by how expensive each tile is to walk onto.
+    ///
+    /// Walls are drawn solid, entrances with their ASCII glyph, and every
+    /// other tile with a shade from `░` to `▓` picked by `Tile::cost`.
+    /// There are no terrain tiles yet and every passable tile costs `1`, so
+    /// for now they are all drawn with the lightest shade.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn render_weighted(&self) -> String {
+        let grid = self
+            .board
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|tile| match *tile {
+                        Tile::Entry => tile.to_char(),
+                        Tile::Wall | Tile::Path | Tile::Item => {
+                            weight_glyph(tile.cost())
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<char>>>();
+
+        join_rows(&grid)
+    }
+
+    /// Converts the maze into rows of ASCII characters.
+    ///
+    /// ### Returns
+    /// * A grid of characters matching the board, using `Glyphs::ascii`.
+    #[inline]
+    #[must_use]
+    pub fn to_char_grid(&self) -> Vec<Vec<char>> {
+        self.render_grid(&Glyphs::ascii())
+    }
+
+    /// Renders the maze with a solution path drawn over it.
+    ///
+    /// The board itself is left untouched, and any `Pair` in `path` that lies
+    /// outside the board is skipped.
+    ///
+    /// ### Parameters
+    /// * `path`: The `Pairs` on the solution path.
+    /// * `glyphs`: The characters to draw each tile and the solution with.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn render_with_solution(
+        &self,
+        path: &[Pair],
+        glyphs: &Glyphs,
+    ) -> String {
+        let mut grid = self.render_grid(glyphs);
+
+        for pair in path {
+            if let Some(glyph) = get_mut_glyph(&mut grid, *pair) {
+                *glyph = glyphs.solution;
+            }
+        }
+
+        join_rows(&grid)
+    }
+
+    /// Renders the maze with its start and end marked.
+    ///
+    /// The board itself is left untouched, and a marker on a wall or outside
+    /// the board is skipped.
+    ///
+    /// ### Parameters
+    /// * `start`: The `Pair` to draw the start glyph on.
+    /// * `end`: The `Pair` to draw the end glyph on.
+    /// * `glyphs`: The characters to draw each tile and the markers with.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn render_with_markers(
+        &self,
+        start: Pair,
+        end: Pair,
+        glyphs: &Glyphs,
+    ) -> String {
+        let mut grid = self.render_grid(glyphs);
+
+        for (pair, marker) in [(start, glyphs.start), (end, glyphs.end)] {
+            if !self
+                .board
+                .get_from_pair(pair)
+                .is_some_and(Tile::is_passable)
+            {
+                continue;
+            }
+            if let Some(glyph) = get_mut_glyph(&mut grid, pair) {
+                *glyph = marker;
+            }
+        }
+
+        join_rows(&grid)
+    }
+
+    /// Converts each tile of the maze to its glyph.
+    ///
+    /// ### Parameters
+    /// * `glyphs`: The characters to draw each tile with.
+    ///
+    /// ### Returns
+    /// * A grid of characters matching the board.
+    fn render_grid(&self, glyphs: &Glyphs) -> Vec<Vec<char>> {
+        self.board
+            .grid
+            .iter()
+            .map(|row| row.iter().map(|tile| glyphs.glyph(tile)).collect())
+            .collect()
+    }
+
+    /// Renders the maze with ANSI escape codes for terminal output.
+    ///
+    /// Colors are disabled when the `NO_COLOR` environment variable is set to
+    /// a non-empty value, in which case plain ASCII is emitted instead.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn to_ansi(&self) -> String {
+        self.to_ansi_with_solution(&[])
+    }
+
+    /// Renders the maze with ANSI escape codes, drawing a solution in red.
+    ///
+    /// Colors are disabled in the same way as `to_ansi`, in which case the
+    /// solution is drawn with the ASCII solution glyph instead.
+    ///
+    /// ### Parameters
+    /// * `path`: The `Pair`s of the solution, where any off the board are
+    ///   skipped.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn to_ansi_with_solution(&self, path: &[Pair]) -> String {
+        self.render_ansi(
+            colors_enabled(env::var_os("NO_COLOR").as_deref()),
+            path,
+        )
+    }
+
+    /// Renders the maze with blocks on UTF-8 terminals and ASCII elsewhere.
+    ///
+    /// The locale is read from the first non-empty of `LC_ALL`, `LC_CTYPE`
+    /// and `LANG`, matching the order the C library checks them in.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn to_display_auto(&self) -> String {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(env::var_os)
+            .find(|value| !value.is_empty());
+        let glyphs = if is_utf8_locale(locale.as_deref()) {
+            Glyphs::blocks()
+        } else {
+            Glyphs::ascii()
+        };
+
+        self.render(&glyphs)
+    }
+
+    /// Renders the walls of the maze as thin, connected box-drawing lines.
+    ///
+    /// Each wall tile is drawn with the character joining it to its
+    /// neighboring wall tiles, while paths and entries are left blank.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn to_box_drawing(&self) -> String {
+        let mut result = String::new();
+
+        for (row_index, row) in self.board.grid.iter().enumerate() {
+            for (col_index, tile) in row.iter().enumerate() {
+                let (Ok(row), Ok(col)) =
+                    (i32::try_from(row_index), i32::try_from(col_index))
+                else {
+                    continue;
+                };
+
+                match *tile {
+                    Tile::Wall => result.push(
+                        self.box_drawing_char(Pair::from_row_and_col(row, col)),
+                    ),
+                    Tile::Path | Tile::Entry | Tile::Item => result.push(' '),
+                }
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+
+    /// Selects the box-drawing character for a wall tile.
+    ///
+    /// ### Parameters
+    /// * `pair`: The position of the wall tile.
+    ///
+    /// ### Returns
+    /// * The character connecting the tile to its neighboring walls.
+    fn box_drawing_char(&self, pair: Pair) -> char {
+        let is_wall = |direction: Direction| {
+            matches!(
+                self.board.get_from_pair(pair.add(Pair::from(direction))),
+                Some(&Tile::Wall)
+            )
+        };
+
+        match (
+            is_wall(Direction::Up),
+            is_wall(Direction::Right),
+            is_wall(Direction::Down),
+            is_wall(Direction::Left),
+        ) {
+            (false, false, false, false) => '·',
+            (_, false, _, false) => '│',
+            (false, _, false, _) => '─',
+            (false, true, true, false) => '┌',
+            (false, false, true, true) => '┐',
+            (true, true, false, false) => '└',
+            (true, false, false, true) => '┘',
+            (true, true, true, false) => '├',
+            (true, false, true, true) => '┤',
+            (false, true, true, true) => '┬',
+            (true, true, false, true) => '┴',
+            (true, true, true, true) => '┼',
+        }
+    }
+
+    /// Renders the maze either with ANSI colors or as plain ASCII.
+    ///
+    /// ### Parameters
+    /// * `colored`: Whether to emit escape sequences.
+    /// * `path`: The `Pair`s of the solution to draw.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    fn render_ansi(&self, colored: bool, path: &[Pair]) -> String {
+        if colored {
+            self.render_colored(ansi_background, "  ", path)
+        } else {
+            self.render_with_solution(path, &Glyphs::ascii())
+        }
+    }
+
+    /// Renders the maze with an ANSI color in front of every tile.
+    ///
+    /// ### Parameters
+    /// * `color`: Gets the escape sequence for each tile.
+    /// * `text`: The text drawn for every tile.
+    /// * `path`: The `Pair`s of a solution to color red instead.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    fn render_colored(
+        &self,
+        color: fn(&Tile) -> &'static str,
+        text: &str,
+        path: &[Pair],
+    ) -> String {
+        let solution = path.iter().copied().collect::<HashSet<Pair>>();
+        let mut result = String::new();
+
+        for (tiles, row) in self.board.rows().zip(0..) {
+            for (tile, col) in tiles.iter().zip(0..) {
+                if solution.contains(&Pair::from_row_and_col(row, col)) {
+                    result.push_str(ANSI_SOLUTION);
+                } else {
+                    result.push_str(color(tile));
+                }
+                result.push_str(text);
+            }
+            result.push_str(ANSI_RESET);
+            result.push('\n');
+        }
+
+        result
+    }
+
+    /// Renders the maze in a named theme.
+    ///
+    /// ### Parameters
+    /// * `theme`: The glyphs and colors to draw the maze with.
+    ///
+    /// ### Returns
+    /// * The rendered maze, one line per row of tiles.
+    #[inline]
+    #[must_use]
+    pub fn render_theme(&self, theme: Theme) -> String {
+        match theme {
+            Theme::Classic => self.render(&Glyphs::blocks()),
+            Theme::HighContrast => {
+                self.render_colored(ansi_foreground, "█", &[])
+            }
+            Theme::Ascii => self.render(&Glyphs::ascii()),
+        }
+    }
+}
+
+/// Joins a grid of characters into lines.
+///
+/// ### Parameters
+/// * `grid`: The characters to join.
+///
+/// ### Returns
+/// * The grid as a string, one line per row.
+fn join_rows(grid: &[Vec<char>]) -> String {
+    let mut result = String::new();
+
+    for row in grid {
+        result.extend(row);
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Gets a mutable reference to a glyph in a grid based on some pair.
+///
+/// ### Parameters
+/// * `grid`: The grid of characters.
+/// * `pair`: The `Pair` object used to access the grid.
+///
+/// ### Returns
+/// * An optional mutable reference to a character in the grid.
+fn get_mut_glyph(grid: &mut [Vec<char>], pair: Pair) -> Option<&mut char> {
+    let row_index = usize::try_from(pair.row).ok()?;
+    let col_index = usize::try_from(pair.col).ok()?;
+    grid.get_mut(row_index)?.get_mut(col_index)
+}
+
+/// Checks whether colored output is allowed.
+///
+/// ### Parameters
+/// * `no_color`: The value of the `NO_COLOR` environment variable, if any.
+///
+/// ### Returns
+/// * `true` unless `NO_COLOR` is set to a non-empty value.
+fn colors_enabled(no_color: Option<&OsStr>) -> bool {
+    no_color.is_none_or(OsStr::is_empty)
+}
+
+/// Checks whether a locale uses the UTF-8 encoding.
+///
+/// ### Parameters
+/// * `locale`: The locale, such as `en_US.UTF-8`, if any is set.
+///
+/// ### Returns
+/// * `true` if the locale names UTF-8 as its codeset.
+fn is_utf8_locale(locale: Option<&OsStr>) -> bool {
+    locale.and_then(OsStr::to_str).is_some_and(|locale| {
+        let codeset = locale
+            .split_once('.')
+            .map_or("", |(_, codeset)| codeset)
+            .split('@')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        codeset == "utf-8" || codeset == "utf8"
+    })
+}
+
+/// Gets the integer code for a tile in CSV output.
+///
+/// ### Parameters
+/// * `tile`: The tile to encode.
+///
+/// ### Returns
+/// * The tile's code.
+const fn csv_code(tile: &Tile) -> u8 {
+    match *tile {
+        Tile::Wall => 0,
+        Tile::Path => 1,
+        Tile::Entry => 2,
+        Tile::Item => 3,
+    }
+}
+
+/// Gets the shade for a tile with some walking cost.
+///
+/// ### Parameters
+/// * `cost`: The cost of walking onto the tile, or `None` if it is
+///   impassable.
+///
+/// ### Returns
+/// * `█` for impassable tiles, otherwise a darker shade the higher the cost.
+const fn weight_glyph(cost: Option<u32>) -> char {
+    match cost {
+        None => '█',
+        Some(0 | 1) => WEIGHT_SHADES[0],
+        Some(2) => WEIGHT_SHADES[1],
+        Some(_) => WEIGHT_SHADES[2],
+    }
+}
+
+/// Gets the escape sequence setting the background color for a tile.
+///
+/// ### Parameters
+/// * `tile`: The tile to color.
+///
+/// ### Returns
+/// * An ANSI escape sequence.
+const fn ansi_background(tile: &Tile) -> &'static str {
+    match *tile {
+        Tile::Wall => "\x1b[40m",
+        Tile::Path => "\x1b[47m",
+        Tile::Entry => "\x1b[42m",
+        Tile::Item => "\x1b[43m",
+    }
+}
+
+/// Gets the escape sequence setting the foreground color for a tile.
+///
+/// ### Parameters
+/// * `tile`: The tile to color.
+///
+/// ### Returns
+/// * An ANSI escape sequence.
+const fn ansi_foreground(tile: &Tile) -> &'static str {
+    match *tile {
+        Tile::Wall => "\x1b[97m",
+        Tile::Path => "\x1b[30m",
+        Tile::Entry => "\x1b[93m",
+        Tile::Item => "\x1b[95m",
+    }
+}
+
+#[cfg(test)]
+mod test_render {
+    use std::ffi::OsStr;
+
+    use crate::board::Board;
+    use crate::glyphs::Glyphs;
+    use crate::maze::Maze;
+    use crate::pair::Pair;
+    use crate::render::{
+        ansi_background, colors_enabled, csv_code, is_utf8_locale,
+        weight_glyph, Theme, ANSI_SOLUTION, WEIGHT_SHADES,
+    };
+    use crate::test_support::maze_from_ascii;
+    use crate::tile::Tile;
+
+    #[test]
+    fn test_to_ansi_colored() {
+        let Some(maze) = Maze::from_backtracking(3, 3) else {
+            panic!("failed to generate maze");
+        };
+
+        let [start, end, ..] = *maze.entry_cells().as_slice() else {
+            panic!("expected two entrances");
+        };
+        let Some(path) = maze.solve_dfs(start, end) else {
+            panic!("failed to solve maze");
+        };
+
+        assert!(colors_enabled(None));
+        let colored = maze.render_ansi(true, &[]);
+        assert!(colored.contains('\x1b'));
+        assert!(!colored.contains(ANSI_SOLUTION));
+
+        let solved = maze.render_ansi(true, &path);
+        assert_eq!(solved.matches(ANSI_SOLUTION).count(), path.len());
+        assert_eq!(
+            solved.replace(ANSI_SOLUTION, ansi_background(&Tile::Path)),
+            colored
+        );
+    }
+
+    #[test]
+    fn test_to_ansi_no_color() {
+        let Some(maze) = Maze::from_backtracking(3, 3) else {
+            panic!("failed to generate maze");
+        };
+
+        let no_color = colors_enabled(Some(OsStr::new("1")));
+        assert!(!no_color);
+        let plain = maze.render_ansi(no_color, &[]);
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.is_ascii());
+        assert_eq!(plain, maze.render(&Glyphs::ascii()));
+
+        let [start, end, ..] = *maze.entry_cells().as_slice() else {
+            panic!("expected two entrances");
+        };
+        let Some(path) = maze.solve_dfs(start, end) else {
+            panic!("failed to solve maze");
+        };
+        let solved = maze.render_ansi(no_color, &path);
+        assert!(!solved.contains('\x1b'));
+        assert_eq!(solved, maze.render_with_solution(&path, &Glyphs::ascii()));
+    }
+
+    #[test]
+    fn test_to_box_drawing_horizontal_wall() {
+        let mut board = Board::<Tile>::new(1, 2);
+        for row in 1..3 {
+            for col in 0..5 {
+                if let Some(tile) =
+                    board.get_mut_from_pair(Pair::from_row_and_col(row, col))
+                {
+                    *tile = Tile::Path;
+                }
+            }
+        }
+        let maze = Maze { board };
+
+        let rendered = maze.to_box_drawing();
+        assert_eq!(rendered.lines().next(), Some("─────"));
+    }
+
+    #[test]
+    fn test_render_custom_entry_glyph() {
+        let Some(maze) = Maze::from_backtracking(4, 4) else {
+            panic!("failed to generate maze");
+        };
+        let glyphs = Glyphs {
+            entry: '@',
+            ..Glyphs::ascii()
+        };
+
+        let rendered = maze.render(&glyphs);
+        for (line, row) in rendered.lines().zip(&maze.board.grid) {
+            for (glyph, tile) in line.chars().zip(row) {
+                assert_eq!(glyph == '@', matches!(*tile, Tile::Entry));
+            }
+        }
+        assert!(rendered.contains('@'));
+    }
+
+    #[test]
+    fn test_render_with_solution_leaves_board_unchanged() {
+        let Some(maze) = Maze::from_backtracking(3, 3) else {
+            panic!("failed to generate maze");
+        };
+        let before = maze.to_string();
+        let path = [
+            Pair::from_row_and_col(1, 1),
+            Pair::from_row_and_col(1, 2),
+            Pair::from_row_and_col(-1, 40),
+        ];
+
+        let rendered = maze.render_with_solution(&path, &Glyphs::ascii());
+        assert_eq!(
+            rendered.lines().nth(1).and_then(|line| line.get(1..3)),
+            Some("..")
+        );
+        assert_eq!(maze.to_string(), before);
+    }
+
+    #[test]
+    fn test_render_theme() {
+        let Some(maze) = Maze::from_backtracking(4, 6) else {
+            panic!("failed to generate maze");
+        };
+
+        assert!(maze.render_theme(Theme::Ascii).is_ascii());
+        assert_eq!(maze.render_theme(Theme::Classic), maze.to_string());
+        assert!(maze.render_theme(Theme::HighContrast).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_render_with_markers() {
+        let Some(maze) = Maze::from_backtracking(2, 3) else {
+            panic!("failed to generate maze");
+        };
+        let before = maze.to_string();
+
+        let rendered = maze.render_with_markers(
+            Pair::from_row_and_col(1, 1),
+            Pair::from_row_and_col(3, 5),
+            &Glyphs::ascii(),
+        );
+        let lines = rendered.lines().collect::<Vec<&str>>();
+        assert_eq!(
+            lines.get(1).and_then(|line| line.chars().nth(1)),
+            Some('S')
+        );
+        assert_eq!(
+            lines.get(3).and_then(|line| line.chars().nth(5)),
+            Some('F')
+        );
+        assert_eq!(maze.to_string(), before);
+
+        let on_wall = maze.render_with_markers(
+            Pair::ZERO,
+            Pair::from_row_and_col(9, 9),
+            &Glyphs::ascii(),
+        );
+        assert_eq!(on_wall, maze.render(&Glyphs::ascii()));
+    }
+
+    #[test]
+    fn test_is_utf8_locale() {
+        assert!(is_utf8_locale(Some(OsStr::new("en_US.UTF-8"))));
+        assert!(is_utf8_locale(Some(OsStr::new("de_DE.utf8@euro"))));
+        assert!(!is_utf8_locale(Some(OsStr::new("C"))));
+        assert!(!is_utf8_locale(Some(OsStr::new("en_US.ISO-8859-1"))));
+        assert!(!is_utf8_locale(None));
+    }
+
+    #[test]
+    fn test_to_char_grid() {
+        let Some(maze) = Maze::from_backtracking(3, 5) else {
+            panic!("failed to generate maze");
+        };
+        let grid = maze.to_char_grid();
+
+        assert_eq!(grid.len(), maze.board.tile_height());
+        assert!(grid.iter().all(|row| row.len() == maze.board.tile_width()));
+        assert_eq!(
+            grid.iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect::<String>(),
+            maze.render(&Glyphs::ascii())
+        );
+    }
+
+    #[test]
+    fn test_row_strings() {
+        let Some(maze) = Maze::from_backtracking(3, 4) else {
+            panic!("failed to generate maze");
+        };
+        let rows = maze.row_strings().collect::<Vec<String>>();
+
+        assert_eq!(rows.len(), maze.board.tile_height());
+        assert_eq!(rows.join("\n") + "\n", maze.to_string());
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let Some(maze) = Maze::from_backtracking(4, 6) else {
+            panic!("failed to generate maze");
+        };
+        let csv = maze.to_csv();
+
+        assert_eq!(csv.lines().count(), maze.board.tile_height());
+        for (line, row) in csv.lines().zip(maze.board.rows()) {
+            let fields = line.split(',').collect::<Vec<&str>>();
+            assert_eq!(fields.len(), maze.board.tile_width());
+            for (field, tile) in fields.iter().zip(row) {
+                assert_eq!(*field, csv_code(tile).to_string());
+            }
+        }
+
+        #[rustfmt::skip]
+        let maze = maze_from_ascii(&[
+            "#E###",
+            "#  *#",
+            "#####",
+        ]);
+        assert_eq!(maze.to_csv(), "0,2,0,0,0\n0,1,1,3,0\n0,0,0,0,0\n");
+    }
+
+    #[test]
+    fn test_render_weighted() {
+        // no tile costs more than 1 yet, so the darker shades can only be
+        // checked through the mapping from cost to shade
+        let shade = |cost: u32| {
+            WEIGHT_SHADES
+                .iter()
+                .position(|glyph| *glyph == weight_glyph(Some(cost)))
+        };
+        assert!(shade(3) > shade(1));
+        assert!(shade(2) > shade(1));
+        assert_eq!(weight_glyph(None), '█');
+
+        #[rustfmt::skip]
+        let maze = maze_from_ascii(&[
+            "#E###",
+            "#  *#",
+            "#####",
+        ]);
+        assert_eq!(maze.render_weighted(), "█E███\n█░░░█\n█████\n");
+    }
+}
@@ -0,0 +1,705 @@
+//! Algorithms for finding paths through a maze.
+
+use core::cmp::Reverse;
+use core::ops::Add;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use strum::IntoEnumIterator;
+
+use crate::board::Board;
+use crate::direction::Direction;
+use crate::edge::Edge;
+use crate::maze::Maze;
+use crate::pair::Pair;
+use crate::stack::Stack;
+use crate::tile::Tile;
+use crate::visit_status::VisitStatus;
+
+/// A policy for how expensive each tile is to walk onto.
+pub trait TileCost {
+    /// Gets the cost of walking onto a tile.
+    ///
+    /// ### Parameters
+    /// * `tile`: The tile being walked onto.
+    ///
+    /// ### Returns
+    /// * The cost of the move, or `None` if the tile is impassable.
+    fn cost(&self, tile: &Tile) -> Option<u32>;
+}
+
+/// Charges one for every passable tile and treats walls as impassable.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitCost;
+
+impl TileCost for UnitCost {
+    #[inline]
+    fn cost(&self, tile: &Tile) -> Option<u32> {
+        tile.cost()
+    }
+}
+
+impl Maze {
+    /// Finds a path between two cells with a depth-first search.
+    ///
+    /// The path is not necessarily the shortest one.
+    ///
+    /// ### Parameters
+    /// * `start`: The cell to start from.
+    /// * `end`: The cell to finish at.
+    ///
+    /// ### Returns
+    /// * The cells on the path from `start` to `end` inclusive, or `None` if
+    ///   either is not an open cell or they are not connected.
+    #[inline]
+    #[must_use]
+    pub fn solve_dfs(&self, start: Pair, end: Pair) -> Option<Vec<Pair>> {
+        if !self.is_open_cell(start) || !self.is_open_cell(end) {
+            return None;
+        }
+
+        let mut visited = Board::<VisitStatus>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        let mut parents = Board::<Option<Pair>>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        *visited.get_mut_from_pair(start)? = VisitStatus::Visited;
+        let mut stack = Stack::from(start);
+
+        while let Some(pair) = stack.pop() {
+            if pair == end {
+                return trace_path(&parents, end);
+            }
+
+            for neighbor in self.connected_cells(pair) {
+                let Some(status) = visited.get_mut_from_pair(neighbor) else {
+                    continue;
+                };
+                if *status == VisitStatus::Unvisited {
+                    *status = VisitStatus::Visited;
+                    *parents.get_mut_from_pair(neighbor)? = Some(pair);
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Finds the cheapest path between two cells with Dijkstra's algorithm.
+    ///
+    /// Moving to a neighboring cell costs the wall tile between the cells plus
+    /// the neighboring cell tile itself.
+    ///
+    /// ### Parameters
+    /// * `start`: The cell to start from.
+    /// * `end`: The cell to finish at.
+    /// * `cost`: The cost of walking onto each tile.
+    ///
+    /// ### Returns
+    /// * The cells on the cheapest path from `start` to `end` inclusive and
+    ///   its total cost, or `None` if no path exists.
+    #[inline]
+    pub fn solve_weighted<C>(
+        &self,
+        start: Pair,
+        end: Pair,
+        cost: &C,
+    ) -> Option<(Vec<Pair>, u32)>
+    where
+        C: TileCost + ?Sized,
+    {
+        if !start.is_cell()
+            || !end.is_cell()
+            || self.board.get_from_pair(end).is_none()
+        {
+            return None;
+        }
+        cost.cost(self.board.get_from_pair(start)?)?;
+
+        let mut distances = Board::<Option<u32>>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        let mut parents = Board::<Option<Pair>>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        *distances.get_mut_from_pair(start)? = Some(0);
+        let mut queue = BinaryHeap::from([Reverse((0, start.row, start.col))]);
+
+        while let Some(Reverse((distance, row, col))) = queue.pop() {
+            let pair = Pair::from_row_and_col(row, col);
+            if pair == end {
+                return Some((trace_path(&parents, end)?, distance));
+            }
+            if *distances.get_from_pair(pair)? != Some(distance) {
+                continue;
+            }
+
+            for direction in Direction::iter() {
+                let neighbor = pair.add(direction.cell_step());
+                let (Some(wall), Some(cell)) = (
+                    self.board.get_from_pair(pair.add(Pair::from(direction))),
+                    self.board.get_from_pair(neighbor),
+                ) else {
+                    continue;
+                };
+                let (Some(wall_cost), Some(cell_cost)) =
+                    (cost.cost(wall), cost.cost(cell))
+                else {
+                    continue;
+                };
+
+                let new_distance = distance
+                    .saturating_add(wall_cost)
+                    .saturating_add(cell_cost);
+                let best = distances.get_mut_from_pair(neighbor)?;
+                if best.is_none_or(|best| new_distance < best) {
+                    *best = Some(new_distance);
+                    *parents.get_mut_from_pair(neighbor)? = Some(pair);
+                    queue.push(Reverse((
+                        new_distance,
+                        neighbor.row,
+                        neighbor.col,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Measures the shortest path between two cells with a breadth-first
+    /// search, without building the path itself.
+    ///
+    /// ### Parameters
+    /// * `start`: The cell to start from.
+    /// * `end`: The cell to finish at.
+    ///
+    /// ### Returns
+    /// * The number of steps between neighboring cells on the shortest path,
+    ///   or `None` if either is not an open cell or they are not connected.
+    #[inline]
+    #[must_use]
+    pub fn shortest_path_length(
+        &self,
+        start: Pair,
+        end: Pair,
+    ) -> Option<usize> {
+        if !self.is_open_cell(start) || !self.is_open_cell(end) {
+            return None;
+        }
+
+        let mut visited = Board::<VisitStatus>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        *visited.get_mut_from_pair(start)? = VisitStatus::Visited;
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((pair, length)) = queue.pop_front() {
+            if pair == end {
+                return Some(length);
+            }
+
+            for neighbor in self.connected_cells(pair) {
+                let Some(status) = visited.get_mut_from_pair(neighbor) else {
+                    continue;
+                };
+                if *status == VisitStatus::Unvisited {
+                    *status = VisitStatus::Visited;
+                    queue.push_back((neighbor, length.add(1)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Solves the maze by filling in dead ends until only the route between
+    /// the first two entrances is left.
+    ///
+    /// This only finds a route in a perfect maze. A loop can never be filled
+    /// in, so any loop left along the route makes the solve fail.
+    ///
+    /// ### Returns
+    /// * The cells on the route from the first entrance to the second
+    ///   inclusive, or `None` if there are fewer than two entrances, they are
+    ///   not connected, or a loop is left between them.
+    #[inline]
+    #[must_use]
+    pub fn solve_by_filling(&self) -> Option<Vec<Pair>> {
+        let [start, end, ..] = *self.entry_cells().as_slice() else {
+            return None;
+        };
+
+        let mut filled = Board::<VisitStatus>::new(
+            self.board.cell_height,
+            self.board.cell_width,
+        );
+        let open_neighbors = |filled: &Board<VisitStatus>, cell: Pair| {
+            self.connected_cells(cell)
+                .into_iter()
+                .filter(|neighbor| {
+                    filled.get_from_pair(*neighbor)
+                        == Some(&VisitStatus::Unvisited)
+                })
+                .collect::<Vec<Pair>>()
+        };
+
+        let mut candidates = self
+            .board
+            .iter_cells()
+            .map(|(pair, _)| pair)
+            .filter(|pair| self.is_open_cell(*pair))
+            .collect::<Vec<Pair>>();
+        while let Some(cell) = candidates.pop() {
+            if cell == start
+                || cell == end
+                || filled.get_from_pair(cell) != Some(&VisitStatus::Unvisited)
+            {
+                continue;
+            }
+            let neighbors = open_neighbors(&filled, cell);
+            if neighbors.len() <= 1 {
+                *filled.get_mut_from_pair(cell)? = VisitStatus::Visited;
+                candidates.extend(neighbors);
+            }
+        }
+
+        let mut path = vec![start];
+        let mut previous = None;
+        let mut current = start;
+        while current != end {
+            let next = match *open_neighbors(&filled, current)
+                .iter()
+                .filter(|neighbor| Some(**neighbor) != previous)
+                .collect::<Vec<&Pair>>()
+                .as_slice()
+            {
+                [next] => *next,
+                _ => return None,
+            };
+            previous = Some(current);
+            current = next;
+            path.push(current);
+        }
+
+        Some(path)
+    }
+
+    /// Finds a path between two cells with Trémaux's algorithm.
+    ///
+    /// Each passage is marked as it is walked. Unmarked passages are taken
+    /// first, an unmarked passage leading back to a visited cell is turned
+    /// back from at once, and a cell with nothing unmarked left is left the
+    /// way it was entered. Passages walked twice are never walked again, so
+    /// the walk ends even when the maze has loops.
+    ///
+    /// ### Parameters
+    /// * `start`: The cell to start from.
+    /// * `end`: The cell to reach.
+    ///
+    /// ### Returns
+    /// * The cells from `start` to `end` inclusive, or `None` if either is
+    ///   not an open cell or there is no path between them.
+    #[inline]
+    #[must_use]
+    pub fn solve_tremaux(&self, start: Pair, end: Pair) -> Option<Vec<Pair>> {
+        if !self.is_open_cell(start) || !self.is_open_cell(end) {
+            return None;
+        }
+
+        let mut marks = HashMap::<Edge, u8>::new();
+        let mut path = vec![start];
+        while let Some(&current) = path.last() {
+            if current == end {
+                return Some(path);
+            }
+
+            let unmarked = self
+                .connected_cells(current)
+                .into_iter()
+                .find(|next| !marks.contains_key(&Edge::new(current, *next)));
+            if let Some(next) = unmarked {
+                let next_visited = self
+                    .connected_cells(next)
+                    .into_iter()
+                    .any(|cell| marks.contains_key(&Edge::new(next, cell)));
+                if next_visited {
+                    marks.insert(Edge::new(current, next), 2);
+                } else {
+                    marks.insert(Edge::new(current, next), 1);
+                    path.push(next);
+                }
+            } else {
+                path.pop();
+                if let Some(&previous) = path.last() {
+                    marks.insert(Edge::new(previous, current), 2);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Checks whether the first two entrances of the maze are connected.
+    ///
+    /// ### Returns
+    /// * `true` if a path joins the cells inside the first two entrances, or
+    ///   `false` if they are not connected or the maze has fewer than two
+    ///   entrances.
+    #[inline]
+    #[must_use]
+    pub fn is_solvable(&self) -> bool {
+        match *self.entry_cells().as_slice() {
+            [start, end, ..] => self.shortest_path_length(start, end).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Gets the cells just inside each entrance of the maze.
+    ///
+    /// ### Returns
+    /// * One cell per `Tile::Entry`, in row-major order of the entrances.
+    pub(crate) fn entry_cells(&self) -> Vec<Pair> {
+        self.board
+            .iter_cells()
+            .filter(|(_, tile)| **tile == Tile::Entry)
+            .filter_map(|(entry, _)| self.entry_cell(entry))
+            .collect()
+    }
+
+    /// Gets the cell that an entrance at some tile would lead into.
+    ///
+    /// ### Parameters
+    /// * `entry`: The `Pair` of the entrance tile.
+    ///
+    /// ### Returns
+    /// * The first cell on the board next to `entry`, in `Direction` order,
+    ///   or `None` if there is none.
+    pub(crate) fn entry_cell(&self, entry: Pair) -> Option<Pair> {
+        Direction::iter()
+            .map(|direction| entry.add(Pair::from(direction)))
+            .find(|pair| {
+                pair.is_cell() && self.board.get_from_pair(*pair).is_some()
+            })
+    }
+
+    /// Checks whether a `Pair` is a cell that can be walked on.
+    ///
+    /// ### Parameters
+    /// * `pair`: The `Pair` to check.
+    ///
+    /// ### Returns
+    /// * `true` if the `Pair` is an odd-indexed, passable tile.
+    fn is_open_cell(&self, pair: Pair) -> bool {
+        pair.is_cell()
+            && self
+                .board
+                .get_from_pair(pair)
+                .is_some_and(Tile::is_passable)
+    }
+
+    /// Gets the neighboring cells that can be reached through a carved wall.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to check the neighbors of.
+    ///
+    /// ### Returns
+    /// * The `Direction` and cell of each reachable neighbor, in `Direction`
+    ///   order.
+    #[inline]
+    #[must_use]
+    pub fn open_neighbors(&self, cell: Pair) -> Vec<(Direction, Pair)> {
+        Direction::iter()
+            .filter(|direction| {
+                self.board
+                    .get_from_pair(cell.add(Pair::from(*direction)))
+                    .is_some_and(Tile::is_passable)
+            })
+            .map(|direction| (direction, cell.add(direction.cell_step())))
+            .filter(|(_, neighbor)| self.is_open_cell(*neighbor))
+            .collect()
+    }
+
+    /// Gets the cells connected to a cell through a carved wall.
+    ///
+    /// ### Parameters
+    /// * `cell`: The cell to check the neighbors of.
+    ///
+    /// ### Returns
+    /// * The neighboring cells that can be walked to.
+    pub(crate) fn connected_cells(&self, cell: Pair) -> Vec<Pair> {
+        self.open_neighbors(cell)
+            .into_iter()
+            .map(|(_, neighbor)| neighbor)
+            .collect()
+    }
+}
+
+/// Follows parent links back from the end of a search.
+///
+/// ### Parameters
+/// * `parents`: The cell each cell was first reached from.
+/// * `end`: The cell the search finished at.
+///
+/// ### Returns
+/// * The cells from the start of the search to `end` inclusive.
+fn trace_path(parents: &Board<Option<Pair>>, end: Pair) -> Option<Vec<Pair>> {
+    let mut path = vec![end];
+    let mut current = end;
+
+    while let Some(parent) = *parents.get_from_pair(current)? {
+        path.push(parent);
+        current = parent;
+    }
+    path.reverse();
+
+    Some(path)
+}
+
+#[cfg(test)]
+mod test_solver {
+    use core::ops::{Add, Div, Sub};
+
+    use crate::{
+        direction::Direction,
+        maze::Maze,
+        pair::Pair,
+        solver::{TileCost, UnitCost},
+        test_support::maze_from_ascii,
+        tile::Tile,
+    };
+
+    /// A cost model that refuses to walk onto entrances.
+    struct AvoidEntries;
+
+    impl TileCost for AvoidEntries {
+        fn cost(&self, tile: &Tile) -> Option<u32> {
+            (*tile == Tile::Path).then_some(1)
+        }
+    }
+
+    /// Asserts that a path walks between adjacent cells over passable tiles.
+    fn assert_connected_path(
+        maze: &Maze,
+        path: &[Pair],
+        start: Pair,
+        end: Pair,
+    ) {
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+
+        for step in path.windows(2) {
+            let [from, to] = *step else {
+                panic!("expected a pair of cells");
+            };
+            let delta = to.sub(from);
+            assert_eq!(delta.row.abs().add(delta.col.abs()), 2);
+
+            let between = from.add(to).div(2);
+            for pair in [from, between, to] {
+                assert!(maze
+                    .board
+                    .get_from_pair(pair)
+                    .is_some_and(Tile::is_passable));
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_dfs() {
+        let Some(maze) = Maze::from_backtracking(6, 8) else {
+            panic!("failed to generate maze");
+        };
+        let start = Pair::from_row_and_col(1, 1);
+        let end = Pair::from_row_and_col(11, 15);
+
+        let Some(path) = maze.solve_dfs(start, end) else {
+            panic!("failed to solve maze");
+        };
+        assert_connected_path(&maze, &path, start, end);
+
+        assert_eq!(maze.solve_dfs(start, Pair::from_row_and_col(0, 0)), None);
+        assert_eq!(Maze::new(2, 2).solve_dfs(start, start), None);
+    }
+
+    #[test]
+    fn test_solve_weighted() {
+        // a ring of four cells, with the top passage marked as an entrance
+        let mut maze = Maze::new(2, 2);
+        for (row, col) in
+            [(1, 1), (1, 3), (3, 1), (3, 3), (2, 1), (2, 3), (3, 2)]
+        {
+            if let Some(tile) = maze
+                .board
+                .get_mut_from_pair(Pair::from_row_and_col(row, col))
+            {
+                *tile = Tile::Path;
+            }
+        }
+        if let Some(tile) =
+            maze.board.get_mut_from_pair(Pair::from_row_and_col(1, 2))
+        {
+            *tile = Tile::Entry;
+        }
+        let start = Pair::from_row_and_col(1, 1);
+        let end = Pair::from_row_and_col(1, 3);
+
+        let Some((path, cost)) = maze.solve_weighted(start, end, &UnitCost)
+        else {
+            panic!("failed to solve maze");
+        };
+        assert_eq!(path, [start, end]);
+        assert_eq!(cost, 2);
+
+        let Some((path, cost)) = maze.solve_weighted(start, end, &AvoidEntries)
+        else {
+            panic!("failed to solve maze");
+        };
+        assert_connected_path(&maze, &path, start, end);
+        assert_eq!(path.len(), 4);
+        assert_eq!(cost, 6);
+    }
+
+    #[test]
+    fn test_shortest_path_length() {
+        #[rustfmt::skip]
+        let maze = maze_from_ascii(&[
+            "#######",
+            "#     #",
+            "# ### #",
+            "#     #",
+            "#######",
+        ]);
+        let start = Pair::from_row_and_col(1, 1);
+        let end = Pair::from_row_and_col(3, 5);
+
+        let Some((path, _)) = maze.solve_weighted(start, end, &UnitCost) else {
+            panic!("failed to solve maze");
+        };
+        assert_eq!(maze.shortest_path_length(start, end), Some(path.len() - 1));
+        assert_eq!(maze.shortest_path_length(start, start), Some(0));
+        assert_eq!(
+            maze.shortest_path_length(start, Pair::from_row_and_col(2, 3)),
+            None
+        );
+        assert_eq!(Maze::new(2, 2).shortest_path_length(start, start), None);
+    }
+
+    #[test]
+    fn test_is_solvable() {
+        for (height, width) in [(1, 1), (1, 8), (7, 3), (12, 12)] {
+            let Some(maze) = Maze::from_backtracking(height, width) else {
+                panic!("failed to generate maze");
+            };
+            assert!(maze.is_solvable());
+        }
+
+        #[rustfmt::skip]
+        let blocked = maze_from_ascii(&[
+            "#E###",
+            "# # #",
+            "###E#",
+        ]);
+        assert!(!blocked.is_solvable());
+        assert!(!Maze::new(3, 3).is_solvable());
+    }
+
+    #[test]
+    fn test_open_neighbors() {
+        #[rustfmt::skip]
+        let maze = maze_from_ascii(&[
+            "###E###",
+            "#     #",
+            "### # #",
+            "#   # #",
+            "#######",
+        ]);
+
+        assert_eq!(
+            maze.open_neighbors(Pair::from_row_and_col(1, 3)),
+            [
+                (Direction::Right, Pair::from_row_and_col(1, 5)),
+                (Direction::Down, Pair::from_row_and_col(3, 3)),
+                (Direction::Left, Pair::from_row_and_col(1, 1)),
+            ]
+        );
+        assert_eq!(
+            maze.open_neighbors(Pair::from_row_and_col(3, 3)),
+            [
+                (Direction::Up, Pair::from_row_and_col(1, 3)),
+                (Direction::Left, Pair::from_row_and_col(3, 1)),
+            ]
+        );
+        assert!(maze.open_neighbors(Pair::ZERO).is_empty());
+    }
+
+    #[test]
+    fn test_solve_by_filling() {
+        let Some(maze) = Maze::from_backtracking(12, 9) else {
+            panic!("failed to generate maze");
+        };
+        let [start, end, ..] = *maze.entry_cells().as_slice() else {
+            panic!("expected two entrances");
+        };
+
+        let Some(path) = maze.solve_by_filling() else {
+            panic!("failed to solve maze");
+        };
+        assert_connected_path(&maze, &path, start, end);
+        assert_eq!(Some(path.len() - 1), maze.shortest_path_length(start, end));
+
+        #[rustfmt::skip]
+        let braided = maze_from_ascii(&[
+            "#E#####",
+            "#     #",
+            "# # # #",
+            "#     #",
+            "#####E#",
+        ]);
+        assert_eq!(braided.solve_by_filling(), None);
+        assert_eq!(Maze::new(2, 2).solve_by_filling(), None);
+    }
+
+    #[test]
+    fn test_solve_tremaux() {
+        // the center cell hangs off a loop, so its walls never touch the
+        // outer wall and following a wall from the entrance circles forever
+        #[rustfmt::skip]
+        let maze = maze_from_ascii(&[
+            "#E#####",
+            "#     #",
+            "# ### #",
+            "# #   #",
+            "# ### #",
+            "#     #",
+            "#######",
+        ]);
+        let start = Pair::from_row_and_col(1, 1);
+        let end = Pair::from_row_and_col(3, 3);
+
+        let Some(path) = maze.solve_tremaux(start, end) else {
+            panic!("failed to solve maze");
+        };
+        assert_connected_path(&maze, &path, start, end);
+        assert!(path
+            .iter()
+            .enumerate()
+            .all(|(index, cell)| !path[index + 1..].contains(cell)));
+
+        let Some(maze) = Maze::from_backtracking(7, 5) else {
+            panic!("failed to generate maze");
+        };
+        let end = Pair::from_row_and_col(13, 9);
+        let Some(path) = maze.solve_tremaux(start, end) else {
+            panic!("failed to solve maze");
+        };
+        assert_eq!(Some(path.len() - 1), maze.shortest_path_length(start, end));
+        assert_eq!(maze.solve_tremaux(start, Pair::ZERO), None);
+    }
+}
@@ -1,5 +1,8 @@
 //! Functionality for a stack container.
 
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result};
+
 /// A basic stack container.
 pub struct Stack<T>
 where
@@ -7,8 +10,23 @@ where
 {
     /// The values in the stack.
     values: Vec<T>,
+    /// The most values the stack may hold, if it is bounded.
+    max: Option<usize>,
+}
+
+/// Signals that a value was pushed onto a full bounded stack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StackFullError;
+
+impl Display for StackFullError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "the stack is full")
+    }
 }
 
+impl Error for StackFullError {}
+
 impl<T> Stack<T>
 where
     T: Copy + Default + Sized,
@@ -28,7 +46,34 @@ where
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        Self { values: vec![] }
+        Self {
+            values: vec![],
+            max: None,
+        }
+    }
+
+    /// Initializes an empty stack that holds at most some number of values.
+    ///
+    /// ### Parameters
+    /// * `max`: The most values the stack may hold.
+    ///
+    /// ### Returns
+    /// * An empty, bounded stack.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::stack::Stack;
+    ///
+    /// let stack = Stack::<i32>::with_max(2);
+    /// assert!(stack.empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_max(max: usize) -> Self {
+        Self {
+            values: vec![],
+            max: Some(max),
+        }
     }
 
     /// Constructs a stack from a value.
@@ -51,10 +96,11 @@ where
     pub fn from(value: T) -> Self {
         Self {
             values: vec![value],
+            max: None,
         }
     }
 
-    /// Pushes a value onto the stack.
+    /// Pushes a value onto the stack, ignoring any maximum size.
     ///
     /// ### Parameters
     /// * `value`: Some value to push on the stack.
@@ -71,6 +117,42 @@ where
         self.values.push(value);
     }
 
+    /// Pushes a value onto the stack if it is not full.
+    ///
+    /// ### Parameters
+    /// * `value`: Some value to push on the stack.
+    ///
+    /// ### Returns
+    /// * `Ok(())` if the value was pushed, or `StackFullError` if a bounded
+    ///   stack is already at its maximum size.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::stack::{Stack, StackFullError};
+    ///
+    /// let mut stack = Stack::<i32>::with_max(2);
+    /// assert_eq!(stack.try_push(1), Ok(()));
+    /// assert_eq!(stack.try_push(2), Ok(()));
+    ///
+    /// assert_eq!(stack.try_push(3), Err(StackFullError));
+    /// assert_eq!(stack.top(), Some(2));
+    ///
+    /// let mut unbounded = Stack::<i32>::new();
+    /// assert!((0..100).all(|value| unbounded.try_push(value).is_ok()));
+    /// ```
+    #[inline]
+    pub fn try_push(
+        &mut self,
+        value: T,
+    ) -> core::result::Result<(), StackFullError> {
+        if self.max.is_some_and(|max| self.values.len() >= max) {
+            return Err(StackFullError);
+        }
+
+        self.values.push(value);
+        Ok(())
+    }
+
     /// Gets the top value from the stack.
     ///
     /// ### Returns
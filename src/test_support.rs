@@ -0,0 +1,96 @@
+//! Helpers shared by the unit tests.
+
+use std::collections::VecDeque;
+
+use rand::{Error, RngCore};
+
+use crate::board::Board;
+use crate::maze::Maze;
+use crate::tile::Tile;
+
+/// Builds a maze from rows of `#` walls, ` ` paths, `E` entrances and `*`
+/// items.
+///
+/// ### Parameters
+/// * `rows`: The tiles of each row.
+///
+/// ### Returns
+/// * The maze drawn by the rows.
+pub fn maze_from_ascii(rows: &[&str]) -> Maze {
+    let grid = rows
+        .iter()
+        .map(|row| {
+            row.chars()
+                .map(|glyph| {
+                    Tile::from_char(glyph)
+                        .unwrap_or_else(|| panic!("unknown tile {glyph:?}"))
+                })
+                .collect()
+        })
+        .collect();
+
+    let Some(board) = Board::from_grid(grid) else {
+        panic!("rows do not form a maze grid");
+    };
+    Maze { board }
+}
+
+/// An `Rng` that yields a predetermined sequence of values.
+pub struct ScriptedRng {
+    /// The values left to yield, in order.
+    values: VecDeque<u32>,
+}
+
+impl ScriptedRng {
+    /// Creates an `Rng` yielding the given values in order.
+    ///
+    /// ### Parameters
+    /// * `values`: The values to yield.
+    ///
+    /// ### Returns
+    /// * A `ScriptedRng` that panics once the values run out.
+    pub fn new(values: impl IntoIterator<Item = u32>) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+        }
+    }
+
+    /// Computes the value that makes a uniform range pick some index.
+    ///
+    /// ### Parameters
+    /// * `index`: The index to pick.
+    /// * `len`: The number of options, which must be larger than `index`.
+    ///
+    /// ### Returns
+    /// * The value for which `gen_range(0..len)` yields `index`.
+    pub fn choice(index: u32, len: u32) -> u32 {
+        let scaled = u64::from(index) << 32_u32;
+        u32::try_from(scaled.div_ceil(u64::from(len))).unwrap_or(u32::MAX)
+    }
+}
+
+impl RngCore for ScriptedRng {
+    fn next_u32(&mut self) -> u32 {
+        self.values
+            .pop_front()
+            .unwrap_or_else(|| panic!("ScriptedRng ran out of values"))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let low = u64::from(self.next_u32());
+        let high = u64::from(self.next_u32());
+        (high << 32_u32) | low
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(bytes.get(..chunk.len()).unwrap_or(&[]));
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -2,7 +2,9 @@
 
 use core::fmt::{Debug, Display, Formatter, Result};
 
-#[derive(Clone)]
+use crate::glyphs::Glyphs;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Tile {
     /// Impassable terrain.
     Wall,
@@ -10,35 +12,105 @@ pub enum Tile {
     Path,
     /// A maze entrance.
     Entry,
+    /// A traversable cell holding an item.
+    Item,
 }
 
-impl Display for Tile {
+impl Tile {
+    /// Checks whether the tile can be walked on.
+    ///
+    /// ### Returns
+    /// * `true` for paths, entrances, and items, otherwise `false`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::tile::Tile;
+    ///
+    /// assert!(Tile::Entry.is_passable());
+    /// assert!(!Tile::Wall.is_passable());
+    /// ```
     #[inline]
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+    #[must_use]
+    pub const fn is_passable(&self) -> bool {
+        matches!(*self, Self::Path | Self::Entry | Self::Item)
+    }
+
+    /// Gets the cost of walking onto the tile.
+    ///
+    /// ### Returns
+    /// * `1` for every passable tile, or `None` for a wall.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::tile::Tile;
+    ///
+    /// assert_eq!(Tile::Item.cost(), Some(1));
+    /// assert_eq!(Tile::Wall.cost(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cost(&self) -> Option<u32> {
         match *self {
-            Self::Path => {
-                write!(
-                    formatter,
-                    "{}",
-                    char::from_u32(0x2B1C).unwrap_or('\u{fffd}')
-                )
-            }
-            Self::Wall => {
-                write!(
-                    formatter,
-                    "{}",
-                    char::from_u32(0x2B1B).unwrap_or('\u{fffd}')
-                )
-            }
-            Self::Entry => {
-                write!(
-                    formatter,
-                    "{}",
-                    char::from_u32(0x1F7E9).unwrap_or('\u{fffd}')
-                )
-            }
+            Self::Wall => None,
+            Self::Path | Self::Entry | Self::Item => Some(1),
         }
     }
+
+    /// Gets the ASCII character for the tile.
+    ///
+    /// ### Returns
+    /// * The tile's glyph from `Glyphs::ascii`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::tile::Tile;
+    ///
+    /// assert_eq!(Tile::Wall.to_char(), '#');
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_char(&self) -> char {
+        Glyphs::ascii().glyph(self)
+    }
+
+    /// Parses a tile from its ASCII or block character.
+    ///
+    /// ### Parameters
+    /// * `glyph`: The character to parse.
+    ///
+    /// ### Returns
+    /// * The tile drawn by the character, or `None` if it is unknown.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{glyphs::Glyphs, tile::Tile};
+    ///
+    /// for tile in [Tile::Wall, Tile::Path, Tile::Entry, Tile::Item] {
+    ///     assert_eq!(Tile::from_char(tile.to_char()), Some(tile.clone()));
+    ///
+    ///     let block = Glyphs::blocks().glyph(&tile);
+    ///     assert_eq!(Tile::from_char(block), Some(tile));
+    /// }
+    /// assert_eq!(Tile::from_char('?'), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_char(glyph: char) -> Option<Self> {
+        [Self::Wall, Self::Path, Self::Entry, Self::Item]
+            .into_iter()
+            .find(|tile| {
+                [Glyphs::ascii(), Glyphs::blocks()]
+                    .iter()
+                    .any(|glyphs| glyphs.glyph(tile) == glyph)
+            })
+    }
+}
+
+impl Display for Tile {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "{}", Glyphs::blocks().glyph(self))
+    }
 }
 
 impl Debug for Tile {
@@ -0,0 +1,179 @@
+//! Transforms that reshape a whole maze.
+
+use core::ops::{Add, Div, Mul, Rem};
+
+use crate::board::{Board, CELL_STEP};
+use crate::maze::Maze;
+use crate::tile::Tile;
+
+impl Maze {
+    /// Rotates the maze a quarter turn clockwise.
+    ///
+    /// A quarter turn carries the perimeter onto the new perimeter, so every
+    /// entrance stays on the outer wall. The cell behind each entrance is
+    /// re-opened in case it was left as a wall.
+    ///
+    /// ### Returns
+    /// * The rotated `Maze`, with its cell dimensions swapped.
+    #[inline]
+    #[must_use]
+    pub fn rotate_90(&self) -> Self {
+        let mut rotated = Self {
+            board: self.board.rotate_90(),
+        };
+
+        for cell in rotated.entry_cells() {
+            if let Some(tile) = rotated.board.get_mut_from_pair(cell) {
+                if *tile == Tile::Wall {
+                    *tile = Tile::Path;
+                }
+            }
+        }
+
+        rotated
+    }
+
+    /// Scales the maze up by replacing every cell with a square block of
+    /// cells.
+    ///
+    /// Each block is opened up inside and takes the tile of the cell it was
+    /// scaled from, while walls stay one tile thick. Two neighboring blocks
+    /// are joined through every cell along their shared side exactly when
+    /// their cells were, so the scaled maze has `factor`-wide corridors but
+    /// the same structure as this one. Each entrance is kept once, in the middle
+    /// of its block's outer side. The pillars between the cells of a block
+    /// stay walls, so the result is still a valid maze.
+    ///
+    /// ### Parameters
+    /// * `factor`: How many cells wide each block is, where `0` is treated as
+    ///   `1` and `1` leaves the maze unchanged.
+    ///
+    /// ### Returns
+    /// * The scaled `Maze`, with its cell dimensions multiplied by `factor`.
+    #[inline]
+    #[must_use]
+    pub fn scale(&self, factor: usize) -> Self {
+        let factor = factor.max(1);
+        // the tiles taken up by a block of cells and the wall line before it
+        let span = factor.mul(CELL_STEP as usize);
+        let middle = factor.div(2).mul(CELL_STEP as usize).add(1);
+        // the original index of a scaled one, and whether it is the middle
+        // of its block
+        let original = |index: usize| {
+            let block = index.div(span);
+            match index.rem(span) {
+                0 => (block.mul(CELL_STEP as usize), true),
+                offset => {
+                    (block.mul(CELL_STEP as usize).add(1), offset == middle)
+                }
+            }
+        };
+        let tile = |row: usize, col: usize| {
+            let is_pillar = row.is_multiple_of(CELL_STEP as usize)
+                && col.is_multiple_of(CELL_STEP as usize)
+                && !(row.is_multiple_of(span) && col.is_multiple_of(span));
+            let ((original_row, middle_row), (original_col, middle_col)) =
+                (original(row), original(col));
+            match self.board.grid.get(original_row)?.get(original_col)? {
+                _ if is_pillar => Some(Tile::Wall),
+                Tile::Entry if !(middle_row && middle_col) => Some(Tile::Wall),
+                tile => Some(tile.clone()),
+            }
+        };
+
+        let cell_height = self.board.cell_height.mul(factor);
+        let cell_width = self.board.cell_width.mul(factor);
+        let grid = (0..Board::<Tile>::cell_position_to_index(cell_height))
+            .map(|row| {
+                (0..Board::<Tile>::cell_position_to_index(cell_width))
+                    .map(|col| tile(row, col).unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            board: Board {
+                grid,
+                cell_width,
+                cell_height,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_transform {
+    use std::collections::HashSet;
+
+    use crate::edge::Edge;
+    use crate::maze::Maze;
+    use crate::pair::Pair;
+    use crate::tile::Tile;
+
+    #[test]
+    fn test_rotate_90() {
+        let Some(maze) = Maze::from_backtracking(4, 7) else {
+            panic!("failed to generate maze");
+        };
+        let rotated = maze.rotate_90();
+
+        assert_eq!(
+            (rotated.board.cell_height, rotated.board.cell_width),
+            (7, 4)
+        );
+        assert_eq!(rotated.validate(), Ok(()));
+        assert!(rotated.is_solvable());
+
+        let path_length = |maze: &Maze| {
+            let [start, end, ..] = *maze.entry_cells().as_slice() else {
+                panic!("expected two entrances");
+            };
+            maze.shortest_path_length(start, end)
+        };
+        assert_eq!(path_length(&rotated), path_length(&maze));
+        assert_eq!(rotated.rotate_90().rotate_90().rotate_90(), maze);
+    }
+
+    #[test]
+    fn test_scale() {
+        let Some(maze) = Maze::from_backtracking(5, 3) else {
+            panic!("failed to generate maze");
+        };
+        let entries =
+            |maze: &Maze| maze.board.count_where(|tile| *tile == Tile::Entry);
+
+        for factor in [2, 3, 4] {
+            let scaled = maze.scale(factor);
+
+            assert_eq!(
+                (scaled.board.cell_height, scaled.board.cell_width),
+                (5 * factor, 3 * factor)
+            );
+            assert_eq!(scaled.validate(), Ok(()));
+            assert!(scaled.is_solvable());
+            assert_eq!(entries(&scaled), 2);
+
+            // two blocks are joined exactly when their original cells were
+            let Ok(block) = i32::try_from(factor) else {
+                panic!("factor out of range");
+            };
+            let block_of = |pair: Pair| {
+                let shrink = |index: i32| (index - 1) / 2 / block * 2 + 1;
+                Pair::from_row_and_col(shrink(pair.row), shrink(pair.col))
+            };
+            let joined = scaled
+                .passages()
+                .into_iter()
+                .map(|edge| {
+                    let (first, second) = edge.pairs;
+                    Edge::new(block_of(first), block_of(second))
+                })
+                .filter(|edge| edge.pairs.0 != edge.pairs.1)
+                .collect::<HashSet<Edge>>();
+            assert_eq!(joined, maze.passages());
+        }
+
+        assert_eq!(maze.scale(1), maze);
+        assert_eq!(maze.scale(0), maze);
+    }
+}
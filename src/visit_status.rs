@@ -8,4 +8,58 @@ pub enum VisitStatus {
     Unvisited,
     /// The cell was previously visited.
     Visited,
+    /// The cell was previously visited at some distance from the start.
+    VisitedAt(u32),
+}
+
+impl VisitStatus {
+    /// Checks whether the cell has been visited.
+    ///
+    /// ### Returns
+    /// * `true` for anything other than `Unvisited`, otherwise `false`.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::visit_status::VisitStatus;
+    ///
+    /// assert!(VisitStatus::VisitedAt(0).is_visited());
+    /// assert!(!VisitStatus::Unvisited.is_visited());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_visited(&self) -> bool {
+        !matches!(*self, Self::Unvisited)
+    }
+
+    /// Gets the distance the cell was visited at.
+    ///
+    /// ### Returns
+    /// * The distance recorded by `VisitedAt`, or `None` if there is none.
+    ///
+    /// ### Examples
+    /// ```
+    /// use maze_generation::{
+    ///     board::Board, pair::Pair, visit_status::VisitStatus,
+    /// };
+    ///
+    /// let mut visited = Board::<VisitStatus>::new(2, 2);
+    /// let cell = Pair::from_row_and_col(1, 3);
+    /// if let Some(status) = visited.get_mut_from_pair(cell) {
+    ///     *status = VisitStatus::VisitedAt(5);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     visited.get_from_pair(cell).and_then(VisitStatus::distance),
+    ///     Some(5)
+    /// );
+    /// assert_eq!(VisitStatus::Visited.distance(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn distance(&self) -> Option<u32> {
+        match *self {
+            Self::VisitedAt(distance) => Some(distance),
+            Self::Unvisited | Self::Visited => None,
+        }
+    }
 }
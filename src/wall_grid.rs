@@ -0,0 +1,138 @@
+//! A compact maze representation storing the walls around each cell.
+
+use core::ops::Add;
+
+use strum::IntoEnumIterator;
+
+use crate::board::Board;
+use crate::direction::{directions_to_mask, mask_to_directions, Direction};
+use crate::maze::Maze;
+use crate::pair::Pair;
+use crate::tile::Tile;
+
+/// A grid of cells, each storing a 4-bit mask of its surrounding walls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WallGrid {
+    /// The wall masks, indexed by cell row and then cell column, with one
+    /// `Direction::bit` set for each closed side.
+    pub masks: Vec<Vec<u8>>,
+    /// The number of cells per row.
+    pub cell_width: usize,
+    /// The number of cells per column.
+    pub cell_height: usize,
+}
+
+impl WallGrid {
+    /// Creates a `WallGrid` from the tiles of a maze.
+    ///
+    /// ### Parameters
+    /// * `maze`: The maze to convert.
+    ///
+    /// ### Returns
+    /// * The `WallGrid` with a wall wherever the maze has a wall tile.
+    #[inline]
+    #[must_use]
+    pub fn from_maze(maze: &Maze) -> Self {
+        let cell_height = maze.board.cell_height;
+        let cell_width = maze.board.cell_width;
+
+        let masks = (0..cell_height)
+            .map(|row| {
+                (0..cell_width)
+                    .map(|col| {
+                        let Some(pair) =
+                            Board::<Tile>::cell_position_to_pair(row, col)
+                        else {
+                            return directions_to_mask(Direction::iter());
+                        };
+
+                        directions_to_mask(Direction::iter().filter(
+                            |direction| {
+                                !matches!(
+                                    maze.board.get_from_pair(
+                                        pair.add(Pair::from(*direction))
+                                    ),
+                                    Some(&Tile::Path | &Tile::Entry)
+                                )
+                            },
+                        ))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            masks,
+            cell_width,
+            cell_height,
+        }
+    }
+}
+
+impl From<WallGrid> for Maze {
+    /// Converts a `WallGrid` back into a tile-based maze.
+    ///
+    /// Missing walls between cells become paths, while missing walls on the
+    /// perimeter become entrances.
+    ///
+    /// ### Parameters
+    /// * `wall_grid`: The `WallGrid` to convert.
+    ///
+    /// ### Returns
+    /// * The equivalent `Maze`.
+    #[inline]
+    fn from(wall_grid: WallGrid) -> Self {
+        let mut board =
+            Board::<Tile>::new(wall_grid.cell_height, wall_grid.cell_width);
+
+        for (row, masks) in wall_grid.masks.iter().enumerate() {
+            for (col, mask) in masks.iter().enumerate() {
+                let Some(pair) = Board::<Tile>::cell_position_to_pair(row, col)
+                else {
+                    continue;
+                };
+                if let Some(tile) = board.get_mut_from_pair(pair) {
+                    *tile = Tile::Path;
+                }
+
+                // the open sides are the directions missing from the mask
+                for direction in mask_to_directions(!*mask) {
+                    let neighbor = pair.add(direction.cell_step());
+                    let opening = if board.get_from_pair(neighbor).is_some() {
+                        Tile::Path
+                    } else {
+                        Tile::Entry
+                    };
+                    if let Some(tile) =
+                        board.get_mut_from_pair(pair.add(Pair::from(direction)))
+                    {
+                        *tile = opening;
+                    }
+                }
+            }
+        }
+
+        Self { board }
+    }
+}
+
+#[cfg(test)]
+mod test_wall_grid {
+    use crate::maze::Maze;
+    use crate::wall_grid::WallGrid;
+
+    #[test]
+    fn test_wall_grid_round_trip() {
+        let Some(maze) = Maze::from_backtracking(5, 7) else {
+            panic!("failed to generate maze");
+        };
+
+        let wall_grid = WallGrid::from_maze(&maze);
+        assert_eq!(wall_grid.masks.len(), 5);
+        assert!(wall_grid.masks.iter().all(|row| row.len() == 7));
+
+        let round_trip = Maze::from(wall_grid.clone());
+        assert_eq!(round_trip.to_string(), maze.to_string());
+        assert_eq!(WallGrid::from_maze(&round_trip), wall_grid);
+    }
+}